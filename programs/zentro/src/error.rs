@@ -1,66 +1,296 @@
-```rust
 use anchor_lang::prelude::*;
 
+/// Every error the program can return.
+///
+/// Variants are grouped by subsystem in blocks of 100 (market lifecycle,
+/// trading, liquidity, oracle, math, resolution, config) and carry explicit
+/// discriminants, so the on-chain code (`6000 + discriminant`) of an
+/// existing variant never changes when new ones are appended to a group.
 #[error_code]
 pub enum ZentroError {
+    // Market lifecycle
     #[msg("Market is not active")]
-    MarketNotActive,
-    
+    MarketNotActive = 0,
+
     #[msg("Market has already been resolved")]
-    MarketAlreadyResolved,
-    
-    #[msg("Insufficient funds for prediction")]
-    InsufficientFunds,
-    
-    #[msg("Invalid prediction amount")]
-    InvalidPredictionAmount,
-    
-    #[msg("Market resolution time has not passed")]
-    ResolutionTimeNotReached,
-    
-    #[msg("Only market creator can resolve")]
-    UnauthorizedResolution,
-    
-    #[msg("Invalid outcome provided")]
-    InvalidOutcome,
-    
-    #[msg("User has no position in this market")]
-    NoPositionFound,
-    
-    #[msg("Cannot claim winnings from losing position")]
-    CannotClaimLosingPosition,
-    
-    #[msg("Winnings already claimed")]
-    WinningsAlreadyClaimed,
-    
-    #[msg("Market creation fee insufficient")]
-    InsufficientCreationFee,
-    
+    MarketAlreadyResolved = 1,
+
+    #[msg("Market has not been resolved")]
+    MarketNotResolved = 2,
+
+    #[msg("Market end time has not passed")]
+    MarketNotEnded = 3,
+
+    #[msg("Market end time has passed")]
+    MarketExpired = 4,
+
+    #[msg("Market end time must be in the future")]
+    InvalidEndTime = 5,
+
     #[msg("Invalid market duration")]
-    InvalidMarketDuration,
-    
+    InvalidMarketDuration = 6,
+
     #[msg("Market title too long")]
-    MarketTitleTooLong,
-    
+    TitleTooLong = 7,
+
     #[msg("Market description too long")]
-    MarketDescriptionTooLong,
-    
-    #[msg("Invalid oracle authority")]
-    InvalidOracleAuthority,
-    
-    #[msg("Oracle has not provided resolution")]
-    OracleResolutionPending,
-    
-    #[msg("Prediction deadline has passed")]
-    PredictionDeadlinePassed,
-    
+    DescriptionTooLong = 8,
+
+    #[msg("Market category too long")]
+    CategoryTooLong = 9,
+
+    #[msg("Invalid outcome provided")]
+    InvalidOutcome = 10,
+
+    #[msg("Account does not belong to this market")]
+    InvalidMarket = 11,
+
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized = 12,
+
+    #[msg("Only market creator can resolve")]
+    UnauthorizedResolution = 13,
+
+    #[msg("Market creation fee insufficient")]
+    InsufficientCreationFee = 14,
+
     #[msg("Cannot cancel resolved market")]
-    CannotCancelResolvedMarket,
-    
+    CannotCancelResolvedMarket = 15,
+
     #[msg("Only market creator can cancel")]
-    UnauthorizedCancellation,
-    
+    UnauthorizedCancellation = 16,
+
     #[msg("Market has active predictions")]
-    MarketHasActivePredictions,
+    MarketHasActivePredictions = 17,
+
+    #[msg("Market must have between 2 and 8 outcomes")]
+    InvalidOutcomeCount = 18,
+
+    #[msg("Outcome title must be non-empty and at most 32 bytes")]
+    InvalidOutcomeTitle = 19,
+
+    #[msg("Scalar lower bound must be below upper bound")]
+    InvalidScalarRange = 20,
+
+    #[msg("Resolution value does not match the market kind")]
+    ResolutionKindMismatch = 21,
+
+    #[msg("Market is paused")]
+    MarketPaused = 22,
+
+    #[msg("No creator deposit to claim")]
+    NoCreatorDeposit = 23,
+
+    #[msg("Market is not cancelled")]
+    MarketNotCancelled = 24,
+
+    #[msg("Market is already cancelled")]
+    MarketAlreadyCancelled = 25,
+
+    #[msg("Vault holds nothing beyond outstanding claims and fees")]
+    NoSurplusToSweep = 26,
+
+    // Trading
+    #[msg("Invalid amount")]
+    InvalidAmount = 100,
+
+    #[msg("Minimum bet must be positive and not exceed maximum bet")]
+    InvalidBetLimits = 101,
+
+    #[msg("Bet amount is below the market minimum")]
+    BetAmountTooLow = 102,
+
+    #[msg("Bet amount is above the market maximum")]
+    BetAmountTooHigh = 103,
+
+    #[msg("Insufficient funds for prediction")]
+    InsufficientFunds = 104,
+
+    #[msg("Position does not belong to signer")]
+    InvalidUser = 105,
+
+    #[msg("User has no position in this market")]
+    NoPositionFound = 106,
+
+    #[msg("Position already holds a different outcome")]
+    PositionOutcomeMismatch = 107,
+
+    #[msg("Insufficient shares to remove")]
+    InsufficientShares = 108,
+
+    #[msg("Cannot claim winnings from losing position")]
+    NotWinningPosition = 109,
+
+    #[msg("No shares were placed on the winning outcome")]
+    NoWinningShares = 110,
+
+    #[msg("No winnings to claim")]
+    NoWinningsToClaim = 111,
+
+    #[msg("Winnings already claimed")]
+    AlreadyClaimed = 112,
+
+    #[msg("Invalid user token account")]
+    InvalidUserTokenAccount = 113,
+
+    #[msg("Invalid vault token account")]
+    InvalidVaultTokenAccount = 114,
+
+    #[msg("Vault balance is insufficient")]
+    InsufficientVaultBalance = 115,
+
+    #[msg("Trade cost exceeds the maximum the user accepted")]
+    MaxCostExceeded = 116,
+
+    #[msg("Trade output is below the minimum the user accepted")]
+    MinAmountOutNotMet = 117,
+
+    #[msg("Trade deadline has passed")]
+    TradeExpired = 118,
+
+    #[msg("No fees to withdraw")]
+    NoFeesToWithdraw = 119,
+
+    #[msg("Collateral mint has an unsupported token extension")]
+    UnsupportedMintExtension = 120,
+
+    #[msg("Limit price must be between 1 and 9999 basis points")]
+    InvalidOrderPrice = 121,
+
+    #[msg("Order book is full and the order does not beat the worst one it would evict")]
+    OrderBookFull = 122,
+
+    #[msg("Order not found")]
+    OrderNotFound = 123,
+
+    #[msg("Maker open orders account is invalid")]
+    InvalidMakerAccount = 124,
+
+    #[msg("Event queue is full; crank consume_events first")]
+    EventQueueFull = 125,
+
+    #[msg("No events could be consumed")]
+    NoEventsToConsume = 126,
+
+    #[msg("No funds to settle")]
+    NoFundsToSettle = 127,
+
+    #[msg("Order is below the book's minimum notional")]
+    OrderTooSmall = 128,
+
+    #[msg("Owner has too many resting orders on this book")]
+    TooManyOpenOrders = 129,
+
+    #[msg("Accounts do not match the market's outcome token mints")]
+    InvalidOutcomeMint = 130,
+
+    #[msg("Every outcome needs a token mint first")]
+    OutcomeMintsMissing = 131,
+
+    #[msg("Token program does not own the collateral mint")]
+    TokenProgramMismatch = 132,
+
+    // Liquidity
+    #[msg("Fee rate exceeds the maximum")]
+    InvalidFeeRate = 200,
+
+    #[msg("Insufficient liquidity")]
+    InsufficientLiquidity = 202,
+
+    #[msg("LMSR liquidity parameter must be positive")]
+    InvalidLiquidityParameter = 203,
+
+    #[msg("LMSR exit profit exceeds the market's remaining subsidy")]
+    ExitExceedsSubsidy = 204,
+
+    // Oracle
+    #[msg("Invalid oracle authority")]
+    InvalidOracleAuthority = 300,
+
+    #[msg("Oracle has not provided resolution")]
+    OracleResolutionPending = 301,
+
+    #[msg("Oracle price was published too long after the market ended")]
+    OraclePriceStale = 302,
+
+    #[msg("Oracle confidence interval is too wide")]
+    OracleConfidenceTooWide = 303,
+
+    #[msg("Account is not a valid oracle price feed")]
+    InvalidOracleAccount = 304,
+
+    #[msg("Oracle price is not currently trading")]
+    OraclePriceUnavailable = 305,
+
+    #[msg("Market is not bound to an oracle")]
+    OracleNotConfigured = 306,
+
+    #[msg("Oracle rule does not fit the market kind")]
+    InvalidOracleConfig = 307,
+
+    #[msg("Market is resolved by its oracle or by proposals, not by its authority")]
+    ManualResolutionDisabled = 308,
+
+    #[msg("Oracle price window has not closed yet")]
+    OracleWindowOpen = 309,
+
+    // Math
+    #[msg("Arithmetic overflow")]
+    MathOverflow = 400,
+
+    // Resolution
+    #[msg("Market does not use optimistic resolution")]
+    OptimisticResolutionDisabled = 500,
+
+    #[msg("Invalid optimistic resolution parameters")]
+    InvalidResolutionConfig = 501,
+
+    #[msg("Challenge window has closed")]
+    ChallengeWindowClosed = 502,
+
+    #[msg("Challenge window is still open")]
+    ChallengeWindowOpen = 503,
+
+    #[msg("Proposal is under dispute")]
+    ProposalDisputed = 504,
+
+    #[msg("Committee needs 1 to 16 distinct members and a threshold above half its size")]
+    InvalidCommitteeConfig = 505,
+
+    #[msg("Committee does not arbitrate this market")]
+    CommitteeMismatch = 506,
+
+    #[msg("Signer is not a member of the resolver committee")]
+    NotCommitteeMember = 507,
+
+    #[msg("Committee member has already voted")]
+    AlreadyVoted = 508,
+
+    #[msg("Market is not under dispute")]
+    MarketNotDisputed = 509,
+
+    #[msg("No resolution has reached the committee threshold")]
+    CommitteeThresholdNotReached = 510,
+
+    #[msg("Market has an open resolution proposal")]
+    ProposalPending = 511,
+
+    #[msg("Committee voting deadline has passed")]
+    VotingClosed = 512,
+
+    #[msg("Dispute is not deadlocked: voting is still open or the committee has decided")]
+    DisputeNotDeadlocked = 513,
+
+    // Config
+    #[msg("Config value out of range")]
+    InvalidConfig = 600,
+
+    #[msg("Signer does not hold the role this action requires")]
+    MissingRole = 601,
+
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin = 602,
+
+    #[msg("Protocol is paused")]
+    ProtocolPaused = 603,
 }
-```
\ No newline at end of file
@@ -0,0 +1,204 @@
+//! Account fixtures shared by the unit tests. Each one is a blank account
+//! that tests adjust through its fields.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    permanent_delegate::PermanentDelegate,
+    transfer_fee::{TransferFee, TransferFeeConfig},
+    ExtensionType, StateWithExtensionsMut,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use bytemuck::Zeroable;
+
+use crate::state::*;
+use crate::utils::lmsr;
+use crate::utils::oracle::{PriceFeed, PRICE_STATUS_TRADING};
+
+/// An active two-outcome LMSR market funded with its full subsidy.
+pub fn lmsr_market(liquidity: u64) -> Market {
+    Market {
+        authority: Pubkey::default(),
+        market_id: 0,
+        title: String::new(),
+        description: String::new(),
+        category: String::new(),
+        status: MarketStatus::Active,
+        kind: MarketKind::Categorical,
+        pricing: PricingEngine::Lmsr { liquidity },
+        subsidy: lmsr::max_subsidy_loss(liquidity, 2).unwrap(),
+        creator_deposit: 0,
+        oracle: None,
+        optimistic: None,
+        creator_fee_bps: 0,
+        protocol_fee_bps: 0,
+        creator_fees_accrued: 0,
+        protocol_fees_accrued: 0,
+        token_mint: Pubkey::default(),
+        end_time: 0,
+        created_at: 0,
+        resolved_at: 0,
+        winning_outcome: None,
+        resolved_value: None,
+        outcomes: vec![
+            MarketOutcome { title: String::new(), total_shares: 0, total_volume: 0 },
+            MarketOutcome { title: String::new(), total_shares: 0, total_volume: 0 },
+        ],
+        outcome_mints: 0,
+        total_volume: 0,
+        total_bets: 0,
+        total_liquidity: 0,
+        total_deposits: 0,
+        total_claimed: 0,
+        total_redeemed: 0,
+        min_bet_amount: 0,
+        max_bet_amount: 0,
+        bump: 0,
+        vault_bump: 0,
+    }
+}
+
+/// Parameters that pass `Config::apply`.
+pub fn config_params() -> ConfigParams {
+    ConfigParams {
+        treasury: Pubkey::new_unique(),
+        protocol_fee_bps: 50,
+        max_creator_fee_bps: 200,
+        max_pool_fee_bps: 300,
+        market_creation_fee: 10_000_000,
+        creation_fee_mint: None,
+        creator_deposit: 100_000_000,
+        crank_fee: 5_000,
+        min_order_notional: 1_000_000,
+        max_title_len: 200,
+        max_description_len: 1000,
+        max_category_len: 32,
+        min_market_duration: 3600,
+        max_market_duration: 365 * 24 * 3600,
+        dispute_timeout: 7 * 24 * 3600,
+        require_market_approval: false,
+    }
+}
+
+pub fn empty_config() -> Config {
+    Config {
+        admin: Pubkey::default(),
+        pending_admin: None,
+        pauser: Pubkey::default(),
+        fee_collector: Pubkey::default(),
+        oracle_manager: Pubkey::default(),
+        market_whitelister: Pubkey::default(),
+        treasury: Pubkey::default(),
+        oracle_program: Pubkey::default(),
+        protocol_fee_bps: 0,
+        max_creator_fee_bps: 0,
+        max_pool_fee_bps: 0,
+        market_creation_fee: 0,
+        creation_fee_mint: None,
+        creator_deposit: 0,
+        crank_fee: 0,
+        min_order_notional: 0,
+        max_title_len: 0,
+        max_description_len: 0,
+        max_category_len: 0,
+        min_market_duration: 0,
+        max_market_duration: 0,
+        dispute_timeout: 0,
+        require_market_approval: false,
+        paused: false,
+        bump: 0,
+    }
+}
+
+/// A pool holding the given reserves, with one LP share per token of the
+/// larger one.
+pub fn liquidity_pool(yes_reserves: u64, no_reserves: u64, fee_rate: u16) -> LiquidityPool {
+    LiquidityPool {
+        authority: Pubkey::default(),
+        market: Pubkey::default(),
+        token_mint: Pubkey::default(),
+        token_vault: Pubkey::default(),
+        yes_token_mint: Pubkey::default(),
+        no_token_mint: Pubkey::default(),
+        yes_token_vault: Pubkey::default(),
+        no_token_vault: Pubkey::default(),
+        total_liquidity: yes_reserves.max(no_reserves),
+        yes_reserves,
+        no_reserves,
+        fee_rate,
+        created_at: 0,
+        bump: 0,
+    }
+}
+
+/// An empty order book; boxed since the slab is too large for the stack.
+pub fn order_book() -> Box<OrderBook> {
+    let mut book = Box::new(OrderBook::zeroed());
+    book.slab.initialize();
+    book
+}
+
+/// An undisputed proposal for outcome 0.
+pub fn proposal() -> ResolutionProposal {
+    ResolutionProposal {
+        market: Pubkey::default(),
+        proposer: Pubkey::default(),
+        resolution: ResolutionValue::Outcome(0),
+        bond: 0,
+        proposed_at: 0,
+        challenge_ends_at: 0,
+        disputer: None,
+        disputed_at: 0,
+        voting_ends_at: 0,
+        votes: Vec::new(),
+        bump: 0,
+    }
+}
+
+/// Account data of a 6-decimal Token-2022 mint carrying `extensions`, only
+/// `TransferFeeConfig` (charging `fee_bps`, capped at 1,000) and
+/// `PermanentDelegate` being supported.
+pub fn mint_with(extensions: &[ExtensionType], fee_bps: u16) -> Vec<u8> {
+    let len = ExtensionType::try_calculate_account_len::<MintState>(extensions).unwrap();
+    let mut data = vec![0u8; len];
+    let mut mint = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+    for extension in extensions {
+        match extension {
+            ExtensionType::TransferFeeConfig => {
+                let config = mint.init_extension::<TransferFeeConfig>(true).unwrap();
+                let fee = TransferFee {
+                    epoch: 0.into(),
+                    maximum_fee: 1_000.into(),
+                    transfer_fee_basis_points: fee_bps.into(),
+                };
+                config.older_transfer_fee = fee;
+                config.newer_transfer_fee = fee;
+            }
+            ExtensionType::PermanentDelegate => {
+                mint.init_extension::<PermanentDelegate>(true).unwrap();
+            }
+            _ => unreachable!(),
+        }
+    }
+    mint.base = MintState {
+        mint_authority: COption::None,
+        supply: 0,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    mint.pack_base();
+    mint.init_account_type().unwrap();
+    data
+}
+
+/// A trading price with 8 decimals.
+pub fn price_feed(price: i64, conf: u64, publish_time: i64) -> PriceFeed {
+    PriceFeed {
+        price,
+        conf,
+        expo: -8,
+        status: PRICE_STATUS_TRADING,
+        publish_time,
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.pending_admin.is_some() @ ZentroError::NoPendingAdmin,
+        constraint = config.pending_admin == Some(new_admin.key()) @ ZentroError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.new_admin.key();
+    config.pending_admin = None;
+
+    emit!(AdminTransferredEvent {
+        previous_admin,
+        new_admin: config.admin,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AdminTransferredEvent {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
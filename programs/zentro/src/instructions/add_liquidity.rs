@@ -1,83 +1,149 @@
-```rust
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
 
 use crate::state::*;
-use crate::errors::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
 
 #[derive(Accounts)]
 pub struct AddLiquidity<'info> {
     #[account(
         mut,
-        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
         bump = market.bump,
     )]
-    pub market: Account<'info, Market>,
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ZentroError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, market.key().as_ref()],
+        bump = pool.bump,
+        has_one = market @ ZentroError::InvalidMarket,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
 
     #[account(
         init_if_needed,
         payer = user,
         space = 8 + LiquidityPosition::INIT_SPACE,
-        seeds = [b"liquidity", market.key().as_ref(), user.key().as_ref()],
+        seeds = [LIQUIDITY_SEED, market.key().as_ref(), user.key().as_ref()],
         bump,
     )]
-    pub liquidity_position: Account<'info, LiquidityPosition>,
+    pub liquidity_position: Box<Account<'info, LiquidityPosition>>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == market.token_mint,
-        constraint = user_token_account.owner == user.key(),
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        seeds = [b"vault", market.key().as_ref()],
+        seeds = [VAULT_SEED, market.key().as_ref()],
         bump = market.vault_bump,
-        constraint = market_vault.mint == market.token_mint,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
     )]
-    pub market_vault: Account<'info, TokenAccount>,
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_mint)]
+    pub yes_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.no_token_mint)]
+    pub no_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_vault)]
+    pub yes_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.no_token_vault)]
+    pub no_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives YES tokens not needed to keep the pool price
+    #[account(
+        mut,
+        constraint = user_yes_account.mint == pool.yes_token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_yes_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub user_yes_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives NO tokens not needed to keep the pool price
+    #[account(
+        mut,
+        constraint = user_no_account.mint == pool.no_token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_no_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub user_no_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, SystemProgram>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> AddLiquidity<'info> {
     pub fn add_liquidity(&mut self, amount: u64, bumps: &AddLiquidityBumps) -> Result<()> {
         require!(amount > 0, ZentroError::InvalidAmount);
-        require!(self.market.is_active, ZentroError::MarketNotActive);
-
-        // Calculate liquidity shares to mint
-        let total_liquidity = self.market.total_liquidity;
-        let vault_balance = self.market_vault.amount;
+        require!(self.market.is_active(), ZentroError::MarketNotActive);
+
+        // Transfer tokens from user to market vault; only what arrives after
+        // any transfer fee is provided as liquidity
+        let amount = transfer_tokens(
+            &self.token_program,
+            &self.token_mint,
+            self.user_token_account.to_account_info(),
+            self.market_vault.to_account_info(),
+            self.user.to_account_info(),
+            &[],
+            amount,
+        )?;
+        require!(amount > 0, ZentroError::InvalidAmount);
 
-        let shares_to_mint = if total_liquidity == 0 {
-            // First liquidity provider gets shares equal to amount
-            amount
-        } else {
-            // Calculate proportional shares
-            (amount as u128)
-                .checked_mul(total_liquidity as u128)
-                .ok_or(ZentroError::MathOverflow)?
-                .checked_div(vault_balance as u128)
-                .ok_or(ZentroError::MathOverflow)? as u64
-        };
-
-        require!(shares_to_mint > 0, ZentroError::InsufficientLiquidity);
-
-        // Transfer tokens from user to market vault
-        let transfer_ctx = CpiContext::new(
-            self.token_program.to_account_info(),
-            Transfer {
-                from: self.user_token_account.to_account_info(),
-                to: self.market_vault.to_account_info(),
-                authority: self.user.to_account_info(),
-            },
-        );
-        token::transfer(transfer_ctx, amount)?;
+        // Each collateral unit backs one YES and one NO token; the pool keeps
+        // what preserves its price and the rest goes back to the provider
+        let (shares_to_mint, yes_returned, no_returned) = self.pool.add_liquidity(amount)?;
+
+        let market_id_bytes = self.market.market_id.to_le_bytes();
+        let market_seeds = &[
+            MARKET_SEED,
+            self.market.authority.as_ref(),
+            market_id_bytes.as_ref(),
+            &[self.market.bump],
+        ];
+        let market_signer = &[&market_seeds[..]];
+
+        let mints = [
+            (&self.yes_token_mint, &self.yes_token_vault, amount - yes_returned),
+            (&self.yes_token_mint, &self.user_yes_account, yes_returned),
+            (&self.no_token_mint, &self.no_token_vault, amount - no_returned),
+            (&self.no_token_mint, &self.user_no_account, no_returned),
+        ];
+        for (mint, to, mint_amount) in mints {
+            if mint_amount == 0 {
+                continue;
+            }
+            let mint_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: self.market.to_account_info(),
+                },
+                market_signer,
+            );
+            token_interface::mint_to(mint_ctx, mint_amount)?;
+        }
 
         // Update liquidity position
         if self.liquidity_position.market == Pubkey::default() {
@@ -96,17 +162,16 @@ impl<'info> AddLiquidity<'info> {
         }
 
         // Update market state
-        self.market.total_liquidity = self
-            .market
-            .total_liquidity
-            .checked_add(shares_to_mint)
-            .ok_or(ZentroError::MathOverflow)?;
+        self.market.total_liquidity = self.pool.total_liquidity;
+        self.market.record_deposit(amount)?;
 
         emit!(LiquidityAddedEvent {
             market: self.market.key(),
             user: self.user.key(),
             amount,
             shares: shares_to_mint,
+            yes_returned,
+            no_returned,
             total_liquidity: self.market.total_liquidity,
         });
 
@@ -120,6 +185,7 @@ pub struct LiquidityAddedEvent {
     pub user: Pubkey,
     pub amount: u64,
     pub shares: u64,
+    pub yes_returned: u64,
+    pub no_returned: u64,
     pub total_liquidity: u64,
 }
-```
\ No newline at end of file
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::slippage::{check_deadline, check_min_amount_out};
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+#[instruction(amount: u64, is_yes: bool)]
+pub struct BuyShares<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = market.end_time > Clock::get()?.unix_timestamp @ ZentroError::MarketExpired
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ZentroError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, market.key().as_ref()],
+        bump = pool.bump,
+        has_one = market @ ZentroError::InvalidMarket,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        address = pool.token_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_mint)]
+    pub yes_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.no_token_mint)]
+    pub no_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_vault)]
+    pub yes_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.no_token_vault)]
+    pub no_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives the bought YES or NO tokens
+    #[account(
+        mut,
+        constraint = user_outcome_account.mint == pool.outcome_mint(is_yes) @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_outcome_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_outcome_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Spends `amount` collateral on YES (`is_yes`) or NO tokens. What remains
+/// after the creator and protocol fees mints complete sets into the pool,
+/// which then swaps the unwanted side for the wanted one along its
+/// constant-product curve. Fails unless at least `min_shares_out` tokens are
+/// received before `expires_at`.
+pub fn buy_shares(
+    ctx: Context<BuyShares>,
+    amount: u64,
+    is_yes: bool,
+    min_shares_out: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+
+    require!(amount > 0, ZentroError::InvalidAmount);
+
+    // Transfer collateral from user to market vault; a Token-2022 transfer
+    // fee reduces what is traded
+    let received = transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        &[],
+        amount,
+    )?;
+
+    // Creator and protocol cuts stay in the vault; the rest buys complete sets
+    let (creator_fee, protocol_fee) = ctx.accounts.market.take_trade_fees(received)?;
+    let sets_amount = received
+        .checked_sub(creator_fee)
+        .and_then(|net| net.checked_sub(protocol_fee))
+        .ok_or(ZentroError::MathOverflow)?;
+    require!(sets_amount > 0, ZentroError::InvalidAmount);
+
+    let pool = &mut ctx.accounts.pool;
+
+    let swap_out = pool.get_swap_amount_out(sets_amount, !is_yes)?;
+    let fee = pool.swap_fee(sets_amount)?;
+    pool.execute_swap(sets_amount, swap_out, !is_yes)?;
+
+    let shares_out = sets_amount.checked_add(swap_out).ok_or(ZentroError::MathOverflow)?;
+    check_min_amount_out(shares_out, min_shares_out)?;
+
+    // Mint the complete sets into the pool
+    let market = &ctx.accounts.market;
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    let sets = [
+        (&ctx.accounts.yes_token_mint, &ctx.accounts.yes_token_vault),
+        (&ctx.accounts.no_token_mint, &ctx.accounts.no_token_vault),
+    ];
+    for (mint, vault) in sets {
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                market_signer,
+            ),
+            sets_amount,
+        )?;
+    }
+
+    // Pay out the bought side from the pool
+    let market_key = market.key();
+    let pool_seeds = &[POOL_SEED, market_key.as_ref(), &[pool.bump]];
+    let pool_signer = &[&pool_seeds[..]];
+
+    let (outcome_mint, pool_vault) = if is_yes {
+        (&ctx.accounts.yes_token_mint, &ctx.accounts.yes_token_vault)
+    } else {
+        (&ctx.accounts.no_token_mint, &ctx.accounts.no_token_vault)
+    };
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        outcome_mint,
+        pool_vault.to_account_info(),
+        ctx.accounts.user_outcome_account.to_account_info(),
+        pool.to_account_info(),
+        pool_signer,
+        shares_out,
+    )?;
+
+    let market = &mut ctx.accounts.market;
+    market.total_volume = market.total_volume.checked_add(received)
+        .ok_or(ZentroError::MathOverflow)?;
+    market.record_deposit(received)?;
+
+    emit!(TradeEvent {
+        market: market.key(),
+        pool: pool.key(),
+        trader: ctx.accounts.user.key(),
+        is_buy: true,
+        is_yes,
+        collateral_amount: amount,
+        shares: shares_out,
+        fee,
+        creator_fee,
+        protocol_fee,
+        yes_reserves: pool.yes_reserves,
+        no_reserves: pool.no_reserves,
+        yes_price: pool.get_current_price()?,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Emitted by `buy_shares` and `sell_shares`.
+#[event]
+pub struct TradeEvent {
+    pub market: Pubkey,
+    pub pool: Pubkey,
+    pub trader: Pubkey,
+    pub is_buy: bool,
+    pub is_yes: bool,
+    /// Collateral paid in on a buy or paid out on a sell
+    pub collateral_amount: u64,
+    /// Outcome tokens received on a buy or sold on a sell
+    pub shares: u64,
+    /// LP fee charged in outcome tokens; it stays in the pool reserves
+    pub fee: u64,
+    /// Creator and protocol cuts in collateral, held in the market vault
+    pub creator_fee: u64,
+    pub protocol_fee: u64,
+    pub yes_reserves: u64,
+    pub no_reserves: u64,
+    /// YES price after the trade, in basis points
+    pub yes_price: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use super::cancel_market::{refund_collateral_deposits, release_creator_deposit, MarketCancelledEvent};
+
+#[derive(Accounts)]
+pub struct CancelDisputedMarket<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = authority @ ZentroError::InvalidMarket,
+        constraint = market.status == MarketStatus::Disputed @ ZentroError::MarketNotDisputed
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Closed to the proposer, which recovers their bond
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, market.key().as_ref()],
+        bump = proposal.bump,
+        has_one = proposer @ ZentroError::InvalidUser,
+        constraint = proposal.disputer == Some(disputer.key()) @ ZentroError::InvalidUser,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ResolutionProposal>,
+
+    #[account(
+        constraint = market.optimistic.is_some_and(|optimistic| optimistic.committee == committee.key())
+            @ ZentroError::CommitteeMismatch
+    )]
+    pub committee: Account<'info, ResolverCommittee>,
+
+    /// CHECK: must be the proposal's proposer; only receives lamports
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: must be the proposal's disputer; only receives lamports
+    #[account(mut)]
+    pub disputer: UncheckedAccount<'info>,
+
+    /// Market creator; receives their deposits back
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Receives the creator's collateral deposits back
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = authority_token_account.owner == authority.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = signer.key() == config.admin
+            || config.has_role(Role::OracleManager, &signer.key()) @ ZentroError::MissingRole
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Cancels a disputed market whose committee let voting close without
+/// reaching its threshold. Both bonds go back to their owners and, as no one
+/// was at fault, so do the creator's deposits. Only the protocol admin or
+/// oracle manager may call it.
+pub fn cancel_disputed_market(ctx: Context<CancelDisputedMarket>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        proposal.is_deadlocked(ctx.accounts.committee.threshold, now),
+        ZentroError::DisputeNotDeadlocked
+    );
+
+    // The disputer's bond is returned here; closing the proposal returns the
+    // proposer's
+    let bond = proposal.bond;
+    let from = proposal.to_account_info();
+    let to = ctx.accounts.disputer.to_account_info();
+    **from.try_borrow_mut_lamports()? = from.lamports()
+        .checked_sub(bond)
+        .ok_or(ZentroError::MathOverflow)?;
+    **to.try_borrow_mut_lamports()? = to.lamports()
+        .checked_add(bond)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    let market = &mut ctx.accounts.market;
+    market.cancel(now)?;
+
+    let deposit = refund_collateral_deposits(
+        market,
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.authority_token_account,
+    )?;
+    let creator_deposit = release_creator_deposit(market, ctx.accounts.authority.to_account_info())?;
+
+    emit!(MarketCancelledEvent {
+        market: market.key(),
+        cancelled_by: ctx.accounts.signer.key(),
+        deposit_returned: deposit.checked_add(creator_deposit).ok_or(ZentroError::MathOverflow)?,
+        deposit_forfeited: 0,
+        cancelled_at: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct CancelMarket<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = authority @ ZentroError::InvalidMarket,
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market creator; receives their deposits back
+    #[account(mut)]
+    pub authority: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Receives the creator's collateral deposits back
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = authority_token_account.owner == authority.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: the market's proposal PDA; must not exist, since its bonds can
+    /// only be released by resolving the market
+    #[account(
+        seeds = [PROPOSAL_SEED, market.key().as_ref()],
+        bump,
+        constraint = proposal.data_is_empty() @ ZentroError::ProposalPending
+    )]
+    pub proposal: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// Receives the creator deposit of a market cancelled as invalid
+    #[account(mut, address = config.treasury @ ZentroError::InvalidConfig)]
+    pub treasury: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Cancels an unresolved market. The creator may do so until anyone pays
+/// collateral in, whether by a bet, trade, split, liquidity or order;
+/// the protocol admin or oracle manager may cancel an invalid question at any
+/// time before resolution, in which case the creator deposit is forfeited.
+/// Markets with an open resolution proposal must be settled through it, or
+/// through `cancel_disputed_market` if its committee deadlocks.
+pub fn cancel_market(ctx: Context<CancelMarket>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let signer = ctx.accounts.signer.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let config = &ctx.accounts.config;
+    let is_privileged = signer == config.admin || config.has_role(Role::OracleManager, &signer);
+
+    if !is_privileged {
+        require_keys_eq!(signer, market.authority, ZentroError::UnauthorizedCancellation);
+        require!(market.total_deposits == 0, ZentroError::MarketHasActivePredictions);
+    }
+
+    market.cancel(now)?;
+
+    // Bettors are refunded their stake, so the creator's deposits go back
+    let deposit = refund_collateral_deposits(
+        market,
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.authority_token_account,
+    )?;
+
+    // The anti-spam deposit goes back too unless someone else had to cancel
+    // the market
+    let (deposit, forfeited) = if signer == market.authority {
+        let to = ctx.accounts.authority.to_account_info();
+        let returned = release_creator_deposit(market, to)?;
+        (deposit.checked_add(returned).ok_or(ZentroError::MathOverflow)?, 0)
+    } else {
+        let to = ctx.accounts.treasury.to_account_info();
+        (deposit, release_creator_deposit(market, to)?)
+    };
+
+    emit!(MarketCancelledEvent {
+        market: market.key(),
+        cancelled_by: signer,
+        deposit_returned: deposit,
+        deposit_forfeited: forfeited,
+        cancelled_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketCancelledEvent {
+    pub market: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub deposit_returned: u64,
+    pub deposit_forfeited: u64,
+    pub cancelled_at: i64,
+}
+
+/// Pays the creator's collateral deposits, the LMSR subsidy and the unpaid
+/// proposer reward, from the vault of a cancelled market to `to`. Returns
+/// the amount paid.
+pub(crate) fn refund_collateral_deposits<'info>(
+    market: &mut Account<'info, Market>,
+    token_program: &Interface<'info, TokenInterface>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<u64> {
+    let reward = market.optimistic.map_or(0, |optimistic| optimistic.reward);
+    let deposit = market.subsidy.checked_add(reward).ok_or(ZentroError::MathOverflow)?;
+    if deposit == 0 {
+        return Ok(0);
+    }
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        token_program,
+        token_mint,
+        vault.to_account_info(),
+        to.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        deposit,
+    )?;
+    market.subsidy = 0;
+
+    Ok(deposit)
+}
+
+/// Moves the anti-spam deposit, held as lamports on the market account, to
+/// `to`. Returns the amount moved.
+pub(crate) fn release_creator_deposit<'info>(
+    market: &mut Account<'info, Market>,
+    to: AccountInfo<'info>,
+) -> Result<u64> {
+    let creator_deposit = market.creator_deposit;
+    if creator_deposit == 0 {
+        return Ok(0);
+    }
+
+    let from = market.to_account_info();
+    **from.try_borrow_mut_lamports()? = from.lamports()
+        .checked_sub(creator_deposit)
+        .ok_or(ZentroError::MathOverflow)?;
+    **to.try_borrow_mut_lamports()? = to.lamports()
+        .checked_add(creator_deposit)
+        .ok_or(ZentroError::MathOverflow)?;
+    market.creator_deposit = 0;
+
+    Ok(creator_deposit)
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(
+        mut,
+        seeds = [ORDER_BOOK_SEED, order_book.load()?.market.as_ref(), &[order_book.load()?.outcome]],
+        bump = order_book.load()?.bump,
+    )]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    #[account(
+        mut,
+        address = order_book.load()?.collateral_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = order_book.load()?.share_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = order_book.load()?.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = order_book.load()?.outcome_mint)]
+    pub outcome_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == token_mint.key() @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_outcome_account.mint == outcome_mint.key() @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_outcome_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_outcome_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Takes the caller's resting order off the book and returns what it still
+/// escrows. Allowed in any market state so funds are never locked in.
+pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u64) -> Result<()> {
+    let order_book_key = ctx.accounts.order_book.key();
+    let mut order_book = ctx.accounts.order_book.load_mut()?;
+
+    let (_, order) = order_book.slab.find(order_id).ok_or(ZentroError::OrderNotFound)?;
+    require_keys_eq!(order.owner, ctx.accounts.user.key(), ZentroError::Unauthorized);
+    let (side, order) = order_book.slab.remove(order_id).ok_or(ZentroError::OrderNotFound)?;
+
+    let (market_key, outcome, bump) = (order_book.market, order_book.outcome, order_book.bump);
+    drop(order_book);
+
+    let book_seeds = &[
+        ORDER_BOOK_SEED,
+        market_key.as_ref(),
+        &[outcome],
+        &[bump],
+    ];
+    let book_signer = &[&book_seeds[..]];
+
+    let (mint, vault, destination, refund) = match side {
+        Side::Bid => (
+            &ctx.accounts.token_mint,
+            &ctx.accounts.collateral_vault,
+            &ctx.accounts.user_token_account,
+            OrderBook::bid_escrow(order.quantity, order.price)?,
+        ),
+        Side::Ask => (
+            &ctx.accounts.outcome_mint,
+            &ctx.accounts.share_vault,
+            &ctx.accounts.user_outcome_account,
+            order.quantity,
+        ),
+    };
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        mint,
+        vault.to_account_info(),
+        destination.to_account_info(),
+        ctx.accounts.order_book.to_account_info(),
+        book_signer,
+        refund,
+    )?;
+
+    emit!(OrderCancelledEvent {
+        market: market_key,
+        order_book: order_book_key,
+        owner: order.owner,
+        order_id,
+        side,
+        price: order.price,
+        quantity: order.quantity,
+        refund,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderCancelledEvent {
+    pub market: Pubkey,
+    pub order_book: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub side: Side,
+    pub price: u16,
+    /// Unfilled quantity taken off the book
+    pub quantity: u64,
+    /// Collateral (bids) or outcome tokens (asks) returned
+    pub refund: u64,
+}
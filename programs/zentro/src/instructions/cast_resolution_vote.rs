@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct CastResolutionVote<'info> {
+    #[account(
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Disputed @ ZentroError::MarketNotDisputed
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, market.key().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ResolutionProposal>,
+
+    #[account(
+        constraint = market.optimistic.is_some_and(|optimistic| optimistic.committee == committee.key())
+            @ ZentroError::CommitteeMismatch,
+        constraint = committee.is_member(&member.key()) @ ZentroError::NotCommitteeMember
+    )]
+    pub committee: Account<'info, ResolverCommittee>,
+
+    pub member: Signer<'info>,
+}
+
+/// Records one committee member's view of how a disputed market resolved.
+pub fn cast_resolution_vote(ctx: Context<CastResolutionVote>, resolution: ResolutionValue) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let proposal = &mut ctx.accounts.proposal;
+    let member = ctx.accounts.member.key();
+
+    require!(
+        proposal.is_voting_open(Clock::get()?.unix_timestamp),
+        ZentroError::VotingClosed
+    );
+    market.validate_resolution(resolution)?;
+    require!(!proposal.has_voted(&member), ZentroError::AlreadyVoted);
+
+    proposal.votes.push(ResolverVote { member, resolution });
+
+    emit!(ResolutionVoteCastEvent {
+        market: market.key(),
+        member,
+        resolution,
+        votes_for_resolution: proposal.votes_for(resolution) as u8,
+        total_votes: proposal.votes.len() as u8,
+        threshold: ctx.accounts.committee.threshold,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResolutionVoteCastEvent {
+    pub market: Pubkey,
+    pub member: Pubkey,
+    pub resolution: ResolutionValue,
+    pub votes_for_resolution: u8,
+    pub total_votes: u8,
+    pub threshold: u8,
+}
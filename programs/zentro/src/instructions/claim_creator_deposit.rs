@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct ClaimCreatorDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = authority @ ZentroError::Unauthorized,
+        constraint = market.is_resolved() @ ZentroError::MarketNotResolved
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Returns the creator's anti-spam deposit once the market has resolved.
+pub fn claim_creator_deposit(ctx: Context<ClaimCreatorDeposit>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    let amount = market.creator_deposit;
+    require!(amount > 0, ZentroError::NoCreatorDeposit);
+
+    // The deposit sits on the market account above its rent-exempt balance
+    let from = market.to_account_info();
+    let to = ctx.accounts.authority.to_account_info();
+    **from.try_borrow_mut_lamports()? = from.lamports()
+        .checked_sub(amount)
+        .ok_or(ZentroError::MathOverflow)?;
+    **to.try_borrow_mut_lamports()? = to.lamports()
+        .checked_add(amount)
+        .ok_or(ZentroError::MathOverflow)?;
+    market.creator_deposit = 0;
+
+    emit!(CreatorDepositClaimedEvent {
+        market: market.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CreatorDepositClaimedEvent {
+    pub market: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+}
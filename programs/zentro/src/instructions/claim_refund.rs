@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.refunds_open() @ ZentroError::MarketNotCancelled
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            market.key().as_ref(),
+            user.key().as_ref(),
+            &[position.outcome]
+        ],
+        bump = position.bump,
+        constraint = position.market == market.key() @ ZentroError::InvalidMarket,
+        constraint = position.user == user.key() @ ZentroError::InvalidUser,
+        constraint = !position.claimed @ ZentroError::AlreadyClaimed
+    )]
+    pub position: Account<'info, UserPosition>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Returns everything a position paid into a cancelled market, or into a
+/// parimutuel market that resolved to an outcome nobody backed.
+pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    let vault = &ctx.accounts.vault;
+
+    let refund = position.total_invested;
+    require!(refund > 0, ZentroError::NoWinningsToClaim);
+    require!(vault.amount >= refund, ZentroError::InsufficientVaultBalance);
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        vault.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        refund,
+    )?;
+
+    position.claimed = true;
+    position.is_active = false;
+
+    market.total_claimed = market.total_claimed.checked_add(refund)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    emit!(RefundClaimedEvent {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        position: position.key(),
+        amount: refund,
+        outcome: position.outcome,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RefundClaimedEvent {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub position: Pubkey,
+    pub amount: u64,
+    pub outcome: u8,
+    pub timestamp: i64,
+}
@@ -1,58 +1,59 @@
-```rust
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
 use crate::state::*;
-use crate::errors::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::pricing::{calculate_payout, calculate_scalar_payout, calculate_share_payout};
+use crate::utils::token::transfer_tokens;
 
 #[derive(Accounts)]
 pub struct ClaimWinnings<'info> {
     #[account(
         mut,
-        seeds = [b"market", market.market_id.to_le_bytes().as_ref()],
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
         bump = market.bump,
-        constraint = market.is_resolved @ ZentroError::MarketNotResolved,
-        constraint = !market.is_closed @ ZentroError::MarketClosed
+        constraint = market.is_resolved() @ ZentroError::MarketNotResolved
     )]
     pub market: Account<'info, Market>,
 
     #[account(
         mut,
         seeds = [
-            b"position",
+            POSITION_SEED,
             market.key().as_ref(),
-            user.key().as_ref()
+            user.key().as_ref(),
+            &[position.outcome]
         ],
         bump = position.bump,
         constraint = position.market == market.key() @ ZentroError::InvalidMarket,
         constraint = position.user == user.key() @ ZentroError::InvalidUser,
         constraint = !position.claimed @ ZentroError::AlreadyClaimed
     )]
-    pub position: Account<'info, Position>,
+    pub position: Account<'info, UserPosition>,
 
     #[account(
         mut,
-        seeds = [b"vault", market.key().as_ref()],
-        bump = vault.bump
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+        constraint = vault.owner == market.key() @ ZentroError::InvalidVaultTokenAccount
     )]
-    pub vault: Account<'info, Vault>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
-    /// CHECK: This is the vault's token account
-    #[account(
-        mut,
-        constraint = vault_token_account.owner == vault.key() @ ZentroError::InvalidVaultTokenAccount
-    )]
-    pub vault_token_account: AccountInfo<'info>,
-
-    /// CHECK: This is the user's token account
     #[account(
         mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
         constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
     )]
-    pub user_token_account: AccountInfo<'info>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -61,63 +62,86 @@ pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
     let position = &mut ctx.accounts.position;
     let vault = &ctx.accounts.vault;
 
-    // Verify the position is on the winning outcome
-    require!(
-        position.outcome == market.winning_outcome.unwrap(),
-        ZentroError::NotWinningPosition
-    );
-
-    // Calculate winnings based on position shares and market resolution
-    let total_winning_shares = match market.winning_outcome.unwrap() {
-        0 => market.total_yes_shares,
-        1 => market.total_no_shares,
-        _ => return Err(ZentroError::InvalidOutcome.into()),
+    let winnings = match (market.pricing, market.kind) {
+        // Each LMSR share redeems its payout weight in collateral
+        (PricingEngine::Lmsr { .. }, _) => {
+            let weight = market.payout_weight(position.outcome)?;
+            require!(weight > 0, ZentroError::NotWinningPosition);
+            calculate_share_payout(position.shares, weight)?
+        }
+        (PricingEngine::Parimutuel, MarketKind::Categorical) => {
+            let winning_outcome = market.winning_outcome.ok_or(ZentroError::MarketNotResolved)?;
+
+            // Verify the position is on the winning outcome
+            require!(
+                position.outcome == winning_outcome,
+                ZentroError::NotWinningPosition
+            );
+
+            // Winning shares split the whole pool pro rata
+            let total_winning_shares = market
+                .outcome(winning_outcome)
+                .ok_or(ZentroError::InvalidOutcome)?
+                .total_shares;
+            let total_pool = market.total_pool().ok_or(ZentroError::MathOverflow)?;
+            calculate_payout(
+                position.shares,
+                position.outcome,
+                winning_outcome,
+                total_winning_shares,
+                total_pool,
+            )?
+        }
+        (PricingEngine::Parimutuel, MarketKind::Scalar { .. }) => {
+            let other_side = if position.outcome == Market::LONG {
+                Market::SHORT
+            } else {
+                Market::LONG
+            };
+            let side_shares = market.outcome(position.outcome).ok_or(ZentroError::InvalidOutcome)?.total_shares;
+            let other_side_shares = market.outcome(other_side).ok_or(ZentroError::InvalidOutcome)?.total_shares;
+
+            // With nobody on the other side there is no one to pay, so this side takes the pool
+            let side_weight = if other_side_shares == 0 {
+                10000
+            } else {
+                market.payout_weight(position.outcome)?
+            };
+
+            let total_pool = market.total_pool().ok_or(ZentroError::MathOverflow)?;
+            calculate_scalar_payout(position.shares, side_weight, side_shares, total_pool)?
+        }
     };
 
-    require!(total_winning_shares > 0, ZentroError::NoWinningShares);
-
-    // Calculate user's share of the total pool
-    let user_share_ratio = position.shares as u128 * 1_000_000 / total_winning_shares as u128;
-    let winnings = (market.total_pool as u128 * user_share_ratio / 1_000_000) as u64;
-
-    require!(winnings > 0, ZentroError::NoWinningsToclaim);
-    require!(vault.balance >= winnings, ZentroError::InsufficientVaultBalance);
+    require!(winnings > 0, ZentroError::NoWinningsToClaim);
+    require!(vault.amount >= winnings, ZentroError::InsufficientVaultBalance);
 
     // Transfer winnings from vault to user
-    let market_key = market.key();
-    let vault_seeds = &[
-        b"vault",
-        market_key.as_ref(),
-        &[vault.bump]
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
     ];
-    let vault_signer = &[&vault_seeds[..]];
-
-    let transfer_instruction = anchor_spl::token::Transfer {
-        from: ctx.accounts.vault_token_account.to_account_info(),
-        to: ctx.accounts.user_token_account.to_account_info(),
-        authority: vault.to_account_info(),
-    };
-
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_instruction,
-        vault_signer,
-    );
-
-    anchor_spl::token::transfer(cpi_ctx, winnings)?;
-
-    // Update vault balance
-    let vault = &mut ctx.accounts.vault;
-    vault.balance = vault.balance.checked_sub(winnings)
-        .ok_or(ZentroError::ArithmeticOverflow)?;
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        winnings,
+    )?;
 
     // Mark position as claimed
     position.claimed = true;
-    position.winnings_claimed = winnings;
 
     // Update market statistics
     market.total_claimed = market.total_claimed.checked_add(winnings)
-        .ok_or(ZentroError::ArithmeticOverflow)?;
+        .ok_or(ZentroError::MathOverflow)?;
 
     emit!(WinningsClaimedEvent {
         market: market.key(),
@@ -129,15 +153,6 @@ pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
         timestamp: Clock::get()?.unix_timestamp,
     });
 
-    msg!(
-        "User {} claimed {} tokens for {} shares on outcome {} in market {}",
-        ctx.accounts.user.key(),
-        winnings,
-        position.shares,
-        position.outcome,
-        market.key()
-    );
-
     Ok(())
 }
 
@@ -151,4 +166,3 @@ pub struct WinningsClaimedEvent {
     pub shares: u64,
     pub timestamp: i64,
 }
-```
\ No newline at end of file
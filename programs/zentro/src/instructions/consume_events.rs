@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct ConsumeEvents<'info> {
+    #[account(
+        seeds = [ORDER_BOOK_SEED, order_book.load()?.market.as_ref(), &[order_book.load()?.outcome]],
+        bump = order_book.load()?.bump,
+    )]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    #[account(mut, address = order_book.load()?.event_queue)]
+    pub event_queue: AccountLoader<'info, EventQueue>,
+
+    /// Earns the book's crank fee for every event consumed
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+/// Permissionless crank: pops up to `limit` fills and evictions off the event
+/// queue and credits each maker's `OpenOrders`, passed writable in
+/// `remaining_accounts`. Stops at the first event whose maker account was
+/// not passed, so the queue is always consumed in order.
+pub fn consume_events<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ConsumeEvents<'info>>,
+    limit: u16,
+) -> Result<()> {
+    let order_book_key = ctx.accounts.order_book.key();
+    let crank_fee = ctx.accounts.order_book.load()?.crank_fee;
+
+    let mut makers = ctx
+        .remaining_accounts
+        .iter()
+        .map(Account::<OpenOrders>::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    for (i, maker) in makers.iter().enumerate() {
+        require_keys_eq!(maker.order_book, order_book_key, ZentroError::InvalidMakerAccount);
+        // A second copy would overwrite the first one's credits on exit
+        require!(
+            makers[..i].iter().all(|other| other.key() != maker.key()),
+            ZentroError::InvalidMakerAccount
+        );
+    }
+
+    let mut event_queue = ctx.accounts.event_queue.load_mut()?;
+    let mut consumed: u16 = 0;
+    while consumed < limit {
+        let Some(event) = event_queue.peek() else { break };
+        let Some(maker) = makers.iter_mut().find(|maker| maker.owner == event.maker) else { break };
+
+        // An evicted order gets back what it escrowed instead of a fill
+        let credit_shares = match event.maker_side() {
+            Side::Bid => event.evicted == 0,
+            Side::Ask => event.evicted != 0,
+        };
+        if credit_shares {
+            maker.free_shares = maker.free_shares.checked_add(event.quantity)
+                .ok_or(ZentroError::MathOverflow)?;
+        } else {
+            maker.free_collateral = maker.free_collateral.checked_add(event.collateral)
+                .ok_or(ZentroError::MathOverflow)?;
+        }
+        event_queue.pop();
+        consumed += 1;
+    }
+    require!(consumed > 0, ZentroError::NoEventsToConsume);
+    let remaining = event_queue.count;
+    drop(event_queue);
+
+    for maker in &makers {
+        maker.exit(&crate::ID)?;
+    }
+
+    // Takers paid the fees into the queue account above its rent-exempt balance
+    let reward = crank_fee
+        .checked_mul(consumed as u64)
+        .ok_or(ZentroError::MathOverflow)?;
+    if reward > 0 {
+        let from = ctx.accounts.event_queue.to_account_info();
+        let to = ctx.accounts.cranker.to_account_info();
+        **from.try_borrow_mut_lamports()? = from.lamports()
+            .checked_sub(reward)
+            .ok_or(ZentroError::MathOverflow)?;
+        **to.try_borrow_mut_lamports()? = to.lamports()
+            .checked_add(reward)
+            .ok_or(ZentroError::MathOverflow)?;
+    }
+
+    emit!(EventsConsumedEvent {
+        order_book: order_book_key,
+        cranker: ctx.accounts.cranker.key(),
+        consumed,
+        reward,
+        remaining,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EventsConsumedEvent {
+    pub order_book: Pubkey,
+    pub cranker: Pubkey,
+    pub consumed: u16,
+    /// Lamports paid to the cranker
+    pub reward: u64,
+    /// Events still queued
+    pub remaining: u32,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+#[instruction(committee_id: u64)]
+pub struct CreateCommittee<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ResolverCommittee::INIT_SPACE,
+        seeds = [COMMITTEE_SEED, authority.key().as_ref(), committee_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub committee: Account<'info, ResolverCommittee>,
+
+    /// Only the admin creates committees, so markets can only name vetted ones
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ ZentroError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a resolver committee that markets may name to arbitrate their
+/// disputes. Admin only.
+pub fn create_committee(
+    ctx: Context<CreateCommittee>,
+    committee_id: u64,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    ResolverCommittee::validate(&members, threshold)?;
+
+    let committee = &mut ctx.accounts.committee;
+    committee.authority = ctx.accounts.authority.key();
+    committee.committee_id = committee_id;
+    committee.members = members;
+    committee.threshold = threshold;
+    committee.bump = ctx.bumps.committee;
+
+    emit!(CommitteeCreatedEvent {
+        committee: committee.key(),
+        authority: committee.authority,
+        members: committee.members.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CommitteeCreatedEvent {
+    pub committee: Pubkey,
+    pub authority: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
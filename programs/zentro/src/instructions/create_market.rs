@@ -1,79 +1,350 @@
-```rust
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
 use crate::state::*;
-use crate::errors::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::lmsr;
+use crate::utils::token::{check_collateral_mint, has_transfer_fee, transfer_tokens};
 
 #[derive(Accounts)]
-#[instruction(market_id: String)]
+#[instruction(market_id: u64)]
 pub struct CreateMarket<'info> {
     #[account(
         init,
         payer = authority,
         space = Market::LEN,
-        seeds = [b"market", market_id.as_bytes()],
+        seeds = [MARKET_SEED, authority.key().as_ref(), market_id.to_le_bytes().as_ref()],
         bump
     )]
     pub market: Account<'info, Market>,
-    
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// Receives the market creation fee
+    #[account(mut, address = config.treasury @ ZentroError::InvalidConfig)]
+    pub treasury: SystemAccount<'info>,
+
+    /// Pays the creation fee when it is charged in an SPL token
+    #[account(mut)]
+    pub fee_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Treasury's account for an SPL creation fee
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Mint of an SPL creation fee
+    pub fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Token program of `fee_mint`, which may differ from the collateral's
+    pub fee_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Collateral of the market: any SPL Token or Token-2022 mint, including
+    /// wrapped SOL, without extensions that break vault accounting
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holds every unit of collateral the market takes in
+    #[account(
+        init,
+        payer = authority,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = market,
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Arbitrates disputes; required with `optimistic` and must be the
+    /// committee it names
+    pub committee: Option<Box<Account<'info, ResolverCommittee>>>,
+
+    /// Funds the LMSR subsidy and the proposer reward, when either is set
+    #[account(mut)]
+    pub authority_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
-    
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_market(
     ctx: Context<CreateMarket>,
-    market_id: String,
+    market_id: u64,
     title: String,
     description: String,
     end_time: i64,
     category: String,
+    kind: MarketKind,
+    pricing: PricingEngine,
+    outcomes: Vec<String>,
+    min_bet_amount: u64,
+    max_bet_amount: u64,
+    oracle: Option<OracleConfig>,
+    mut optimistic: Option<OptimisticConfig>,
+    creator_fee_bps: u16,
 ) -> Result<()> {
+    let config = &ctx.accounts.config;
     let market = &mut ctx.accounts.market;
     let clock = Clock::get()?;
-    
+
     require!(
         end_time > clock.unix_timestamp,
         ZentroError::InvalidEndTime
     );
-    
+
+    let duration = end_time - clock.unix_timestamp;
+    require!(
+        duration >= config.min_market_duration && duration <= config.max_market_duration,
+        ZentroError::InvalidMarketDuration
+    );
+
     require!(
-        title.len() <= 200,
+        title.len() <= config.max_title_len as usize,
         ZentroError::TitleTooLong
     );
-    
+
     require!(
-        description.len() <= 1000,
+        description.len() <= config.max_description_len as usize,
         ZentroError::DescriptionTooLong
     );
-    
+
+    require!(
+        category.len() <= config.max_category_len as usize,
+        ZentroError::CategoryTooLong
+    );
+
+    require!(
+        creator_fee_bps <= config.max_creator_fee_bps,
+        ZentroError::InvalidFeeRate
+    );
+
+    let mint_data = ctx.accounts.token_mint.to_account_info().try_borrow_data()?.to_vec();
+    check_collateral_mint(&mint_data)?;
+
+    // Scalar markets always trade exactly LONG and SHORT
+    let outcomes = match kind {
+        MarketKind::Categorical => outcomes,
+        MarketKind::Scalar { lower_bound, upper_bound } => {
+            require!(lower_bound < upper_bound, ZentroError::InvalidScalarRange);
+            require!(outcomes.is_empty(), ZentroError::InvalidOutcomeCount);
+            vec!["Long".to_string(), "Short".to_string()]
+        }
+    };
+
+    require!(
+        outcomes.len() >= Market::MIN_OUTCOMES && outcomes.len() <= Market::MAX_OUTCOMES,
+        ZentroError::InvalidOutcomeCount
+    );
+
+    require!(
+        outcomes
+            .iter()
+            .all(|title| !title.is_empty() && title.len() <= Market::MAX_OUTCOME_TITLE_LEN),
+        ZentroError::InvalidOutcomeTitle
+    );
+
+    // LMSR markets are seeded with the market maker's worst-case loss
+    let subsidy = match pricing {
+        PricingEngine::Parimutuel => 0,
+        PricingEngine::Lmsr { liquidity } => lmsr::max_subsidy_loss(liquidity, outcomes.len())?,
+    };
+
     require!(
-        market_id.len() <= 50,
-        ZentroError::MarketIdTooLong
+        min_bet_amount > 0 && min_bet_amount <= max_bet_amount,
+        ZentroError::InvalidBetLimits
     );
-    
+
+    // LMSR prices a fixed number of shares, so every unit of cost must reach
+    // the vault
+    if matches!(pricing, PricingEngine::Lmsr { .. }) {
+        require!(!has_transfer_fee(&mint_data)?, ZentroError::UnsupportedMintExtension);
+    }
+
+    if let Some(optimistic) = &optimistic {
+        require!(
+            oracle.is_none() && optimistic.bond > 0 && optimistic.challenge_period > 0,
+            ZentroError::InvalidResolutionConfig
+        );
+        // Committees are created by the admin only, so any that deserializes
+        // is one the protocol vetted
+        let committee = ctx.accounts.committee.as_ref().ok_or(ZentroError::CommitteeMismatch)?;
+        require_keys_eq!(committee.key(), optimistic.committee, ZentroError::CommitteeMismatch);
+    }
+
+    // The vault also holds the reward for an undisputed resolution proposal
+    let deposit = optimistic
+        .map_or(0, |optimistic| optimistic.reward)
+        .checked_add(subsidy)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    let creation_fee = config.market_creation_fee;
+    if creation_fee > 0 {
+        match config.creation_fee_mint {
+            None => {
+                require!(
+                    ctx.accounts.authority.lamports() >= creation_fee,
+                    ZentroError::InsufficientCreationFee
+                );
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.authority.to_account_info(),
+                            to: ctx.accounts.treasury.to_account_info(),
+                        },
+                    ),
+                    creation_fee,
+                )?;
+            }
+            Some(fee_mint) => {
+                let (Some(from), Some(to), Some(mint), Some(fee_token_program)) = (
+                    &ctx.accounts.fee_token_account,
+                    &ctx.accounts.treasury_token_account,
+                    &ctx.accounts.fee_mint,
+                    &ctx.accounts.fee_token_program,
+                ) else {
+                    return err!(ZentroError::InsufficientCreationFee);
+                };
+                require_keys_eq!(mint.key(), fee_mint, ZentroError::InvalidConfig);
+                require_keys_eq!(
+                    *mint.to_account_info().owner,
+                    fee_token_program.key(),
+                    ZentroError::InvalidConfig
+                );
+                require!(
+                    from.mint == fee_mint
+                        && from.owner == ctx.accounts.authority.key()
+                        && from.amount >= creation_fee,
+                    ZentroError::InsufficientCreationFee
+                );
+                require!(
+                    to.mint == fee_mint && to.owner == config.treasury,
+                    ZentroError::InvalidConfig
+                );
+                transfer_tokens(
+                    fee_token_program,
+                    mint,
+                    from.to_account_info(),
+                    to.to_account_info(),
+                    ctx.accounts.authority.to_account_info(),
+                    &[],
+                    creation_fee,
+                )?;
+            }
+        }
+    }
+
+    if deposit > 0 {
+        let Some(from) = &ctx.accounts.authority_token_account else {
+            return err!(ZentroError::InvalidUserTokenAccount);
+        };
+        require!(
+            from.mint == ctx.accounts.token_mint.key() && from.owner == ctx.accounts.authority.key(),
+            ZentroError::InvalidUserTokenAccount
+        );
+        let received = transfer_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.token_mint,
+            from.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            &[],
+            deposit,
+        )?;
+
+        // Only fee-free mints back a subsidy, so a transfer fee comes out of
+        // the proposer reward
+        if let Some(optimistic) = optimistic.as_mut() {
+            optimistic.reward -= deposit - received;
+        }
+    }
+
+    // The anti-spam deposit stays in SOL whatever the collateral, so it is
+    // kept on the market account itself
+    if config.creator_deposit > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: market.to_account_info(),
+                },
+            ),
+            config.creator_deposit,
+        )?;
+    }
+
     market.authority = ctx.accounts.authority.key();
     market.market_id = market_id;
     market.title = title;
     market.description = description;
     market.category = category;
+    market.status = if config.require_market_approval {
+        MarketStatus::Draft
+    } else {
+        MarketStatus::Active
+    };
+    market.kind = kind;
+    market.pricing = pricing;
+    market.subsidy = subsidy;
+    market.creator_deposit = config.creator_deposit;
+    market.token_mint = ctx.accounts.token_mint.key();
     market.end_time = end_time;
     market.created_at = clock.unix_timestamp;
-    market.is_resolved = false;
-    market.resolution_outcome = None;
-    market.total_yes_amount = 0;
-    market.total_no_amount = 0;
+    market.resolved_at = 0;
+    market.winning_outcome = None;
+    market.resolved_value = None;
+    market.outcomes = outcomes
+        .into_iter()
+        .map(|title| MarketOutcome {
+            title,
+            total_shares: 0,
+            total_volume: 0,
+        })
+        .collect();
+    market.outcome_mints = 0;
+    market.total_volume = 0;
+    market.total_bets = 0;
+    market.total_liquidity = 0;
+    market.total_deposits = 0;
+    market.total_claimed = 0;
+    market.total_redeemed = 0;
+    market.min_bet_amount = min_bet_amount;
+    market.max_bet_amount = max_bet_amount;
     market.bump = ctx.bumps.market;
-    
+    market.vault_bump = ctx.bumps.vault;
+
+    if let Some(oracle) = &oracle {
+        market.validate_oracle(oracle)?;
+    }
+    market.oracle = oracle;
+    market.optimistic = optimistic;
+    market.creator_fee_bps = creator_fee_bps;
+    market.protocol_fee_bps = config.protocol_fee_bps;
+    market.creator_fees_accrued = 0;
+    market.protocol_fees_accrued = 0;
+
     emit!(MarketCreated {
         market: market.key(),
         authority: market.authority,
-        market_id: market.market_id.clone(),
+        market_id: market.market_id,
         title: market.title.clone(),
+        kind: market.kind,
+        pricing: market.pricing,
+        subsidy: market.subsidy,
+        creator_deposit: market.creator_deposit,
+        oracle: market.oracle,
+        optimistic: market.optimistic,
+        creator_fee_bps: market.creator_fee_bps,
+        protocol_fee_bps: market.protocol_fee_bps,
+        outcome_count: market.outcomes.len() as u8,
         end_time: market.end_time,
         created_at: market.created_at,
     });
-    
+
     Ok(())
 }
 
@@ -81,9 +352,17 @@ pub fn create_market(
 pub struct MarketCreated {
     pub market: Pubkey,
     pub authority: Pubkey,
-    pub market_id: String,
+    pub market_id: u64,
     pub title: String,
+    pub kind: MarketKind,
+    pub pricing: PricingEngine,
+    pub subsidy: u64,
+    pub creator_deposit: u64,
+    pub oracle: Option<OracleConfig>,
+    pub optimistic: Option<OptimisticConfig>,
+    pub creator_fee_bps: u16,
+    pub protocol_fee_bps: u16,
+    pub outcome_count: u8,
     pub end_time: i64,
     pub created_at: i64,
 }
-```
\ No newline at end of file
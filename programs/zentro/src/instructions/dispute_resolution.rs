@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct DisputeResolution<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_open() @ ZentroError::MarketNotActive
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, market.key().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.is_disputed() @ ZentroError::ProposalDisputed
+    )]
+    pub proposal: Account<'info, ResolutionProposal>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Challenges a pending proposal by matching its bond, which moves the market
+/// to `Disputed` until it is arbitrated. The committee has
+/// `Config::dispute_timeout` seconds to vote. Paused markets cannot be
+/// disputed until unpaused.
+pub fn dispute_resolution(ctx: Context<DisputeResolution>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now < proposal.challenge_ends_at, ZentroError::ChallengeWindowClosed);
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.disputer.to_account_info(),
+                to: proposal.to_account_info(),
+            },
+        ),
+        proposal.bond,
+    )?;
+
+    proposal.disputer = Some(ctx.accounts.disputer.key());
+    proposal.disputed_at = now;
+    proposal.voting_ends_at = now
+        .checked_add(ctx.accounts.config.dispute_timeout)
+        .ok_or(ZentroError::MathOverflow)?;
+    market.dispute()?;
+
+    emit!(ResolutionDisputedEvent {
+        market: market.key(),
+        proposer: proposal.proposer,
+        disputer: ctx.accounts.disputer.key(),
+        bond: proposal.bond,
+        disputed_at: now,
+        voting_ends_at: proposal.voting_ends_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResolutionDisputedEvent {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub disputer: Pubkey,
+    pub bond: u64,
+    pub disputed_at: i64,
+    pub voting_ends_at: i64,
+}
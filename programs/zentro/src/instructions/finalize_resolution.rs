@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+use super::resolve_market::MarketResolvedEvent;
+
+#[derive(Accounts)]
+pub struct FinalizeResolution<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_open() @ ZentroError::MarketNotActive
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Closed to the proposer, which returns the bond with the rent
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, market.key().as_ref()],
+        bump = proposal.bump,
+        has_one = proposer @ ZentroError::InvalidUser,
+        constraint = !proposal.is_disputed() @ ZentroError::ProposalDisputed,
+        close = proposer
+    )]
+    pub proposal: Account<'info, ResolutionProposal>,
+
+    /// CHECK: matched against `proposal.proposer`; only receives lamports
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Receives the proposer reward
+    #[account(
+        mut,
+        constraint = proposer_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = proposer_token_account.owner == proposer.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub proposer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Settles the market on an unchallenged proposal once its window has passed.
+/// Anyone may call it.
+pub fn finalize_resolution(ctx: Context<FinalizeResolution>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let proposal = &ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= proposal.challenge_ends_at, ZentroError::ChallengeWindowOpen);
+
+    market.resolve(proposal.resolution, now)?;
+
+    let reward = market.optimistic.map_or(0, |optimistic| optimistic.reward);
+    if reward > 0 {
+        let market_id_bytes = market.market_id.to_le_bytes();
+        let market_seeds = &[
+            MARKET_SEED,
+            market.authority.as_ref(),
+            market_id_bytes.as_ref(),
+            &[market.bump],
+        ];
+        let market_signer = &[&market_seeds[..]];
+
+        transfer_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.token_mint,
+            ctx.accounts.vault.to_account_info(),
+            ctx.accounts.proposer_token_account.to_account_info(),
+            market.to_account_info(),
+            market_signer,
+            reward,
+        )?;
+    }
+
+    emit!(ResolutionFinalizedEvent {
+        market: market.key(),
+        proposer: proposal.proposer,
+        resolution: proposal.resolution,
+        bond_returned: proposal.bond,
+        reward,
+    });
+
+    emit!(MarketResolvedEvent {
+        market: market.key(),
+        resolution: proposal.resolution,
+        resolved_at: market.resolved_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResolutionFinalizedEvent {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub resolution: ResolutionValue,
+    pub bond_returned: u64,
+    pub reward: u64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Only the program's upgrade authority may create the config
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ZentroError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // The admin holds every role until it delegates them
+    let admin = ctx.accounts.admin.key();
+    config.admin = admin;
+    config.pending_admin = None;
+    config.pauser = admin;
+    config.fee_collector = admin;
+    config.oracle_manager = admin;
+    config.market_whitelister = admin;
+    config.oracle_program = Pubkey::default();
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+    config.apply(params)?;
+
+    emit!(ConfigUpdatedEvent {
+        admin: config.admin,
+        params,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub params: ConfigParams,
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::has_transfer_fee;
+
+#[derive(Accounts)]
+#[instruction(outcome: u8)]
+pub struct InitializeOrderBook<'info> {
+    #[account(
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = (outcome as usize) < market.outcomes.len() @ ZentroError::InvalidOutcome,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = OrderBook::LEN,
+        seeds = [ORDER_BOOK_SEED, market.key().as_ref(), &[outcome]],
+        bump,
+    )]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = EventQueue::LEN,
+        seeds = [EVENT_QUEUE_SEED, order_book.key().as_ref()],
+        bump,
+    )]
+    pub event_queue: AccountLoader<'info, EventQueue>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(seeds = [OUTCOME_MINT_SEED, market.key().as_ref(), &[outcome]], bump)]
+    pub outcome_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [ORDER_VAULT_SEED, order_book.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = order_book,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [ORDER_VAULT_SEED, order_book.key().as_ref(), outcome_mint.key().as_ref()],
+        bump,
+        token::mint = outcome_mint,
+        token::authority = order_book,
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens a limit order book for the token of one outcome of a market, along
+/// with its event queue. Anyone may pay for it; on binary markets it trades
+/// alongside the pool.
+pub fn initialize_order_book(ctx: Context<InitializeOrderBook>, outcome: u8) -> Result<()> {
+    // Fills are paid maker to taker in the exact amounts matched
+    require!(
+        !has_transfer_fee(&ctx.accounts.token_mint.to_account_info().try_borrow_data()?)?,
+        ZentroError::UnsupportedMintExtension
+    );
+
+    let order_book_key = ctx.accounts.order_book.key();
+    let mut order_book = ctx.accounts.order_book.load_init()?;
+    order_book.market = ctx.accounts.market.key();
+    order_book.outcome = outcome;
+    order_book.token_mint = ctx.accounts.token_mint.key();
+    order_book.outcome_mint = ctx.accounts.outcome_mint.key();
+    order_book.collateral_vault = ctx.accounts.collateral_vault.key();
+    order_book.share_vault = ctx.accounts.share_vault.key();
+    order_book.event_queue = ctx.accounts.event_queue.key();
+    order_book.next_order_id = 0;
+    order_book.crank_fee = ctx.accounts.config.crank_fee;
+    order_book.min_order_notional = ctx.accounts.config.min_order_notional;
+    order_book.bump = ctx.bumps.order_book;
+    order_book.slab.initialize();
+
+    let mut event_queue = ctx.accounts.event_queue.load_init()?;
+    event_queue.order_book = order_book_key;
+
+    emit!(OrderBookInitializedEvent {
+        market: order_book.market,
+        order_book: order_book_key,
+        event_queue: order_book.event_queue,
+        outcome,
+        outcome_mint: order_book.outcome_mint,
+        crank_fee: order_book.crank_fee,
+        min_order_notional: order_book.min_order_notional,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OrderBookInitializedEvent {
+    pub market: Pubkey,
+    pub order_book: Pubkey,
+    pub event_queue: Pubkey,
+    pub outcome: u8,
+    pub outcome_mint: Pubkey,
+    pub crank_fee: u64,
+    pub min_order_notional: u64,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::is_owned_by_program;
+
+#[derive(Accounts)]
+#[instruction(outcome: u8)]
+pub struct InitializeOutcomeMint<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = (outcome as usize) < market.outcomes.len() @ ZentroError::InvalidOutcome,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        address = market.token_mint,
+        constraint = is_owned_by_program(&token_mint.to_account_info(), &token_program.key())
+            @ ZentroError::TokenProgramMismatch
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [OUTCOME_MINT_SEED, market.key().as_ref(), &[outcome]],
+        bump,
+        mint::decimals = token_mint.decimals,
+        mint::authority = market,
+    )]
+    pub outcome_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the token mint of one outcome of a market under the collateral's
+/// token program. Anyone may pay for it; once every outcome has a mint,
+/// collateral can be split into complete sets of transferable outcome
+/// tokens.
+pub fn initialize_outcome_mint(ctx: Context<InitializeOutcomeMint>, outcome: u8) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    market.outcome_mints = market.outcome_mints.checked_add(1)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    emit!(OutcomeMintInitializedEvent {
+        market: market.key(),
+        outcome,
+        outcome_mint: ctx.accounts.outcome_mint.key(),
+        outcome_mints: market.outcome_mints,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OutcomeMintInitializedEvent {
+    pub market: Pubkey,
+    pub outcome: u8,
+    pub outcome_mint: Pubkey,
+    /// Outcome mints the market has after this one
+    pub outcome_mints: u8,
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = authority @ ZentroError::Unauthorized,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = market.outcomes.len() == 2 @ ZentroError::InvalidOutcomeCount,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = LiquidityPool::LEN,
+        seeds = [POOL_SEED, market.key().as_ref()],
+        bump,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Outcome 0 mint, created by `initialize_outcome_mint`
+    #[account(
+        seeds = [OUTCOME_MINT_SEED, market.key().as_ref(), &[LiquidityPool::YES_OUTCOME]],
+        bump,
+    )]
+    pub yes_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Outcome 1 mint, created by `initialize_outcome_mint`
+    #[account(
+        seeds = [OUTCOME_MINT_SEED, market.key().as_ref(), &[LiquidityPool::NO_OUTCOME]],
+        bump,
+    )]
+    pub no_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [POOL_VAULT_SEED, pool.key().as_ref(), yes_token_mint.key().as_ref()],
+        bump,
+        token::mint = yes_token_mint,
+        token::authority = pool,
+    )]
+    pub yes_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [POOL_VAULT_SEED, pool.key().as_ref(), no_token_mint.key().as_ref()],
+        bump,
+        token::mint = no_token_mint,
+        token::authority = pool,
+    )]
+    pub no_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn initialize_pool(ctx: Context<InitializePool>, fee_rate: u16) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let pool = &mut ctx.accounts.pool;
+
+    require!(
+        fee_rate <= ctx.accounts.config.max_pool_fee_bps,
+        ZentroError::InvalidFeeRate
+    );
+
+    // Pool collateral is held in the market vault alongside every other deposit
+    pool.initialize(
+        ctx.accounts.authority.key(),
+        market.key(),
+        market.token_mint,
+        ctx.accounts.market_vault.key(),
+        ctx.accounts.yes_token_mint.key(),
+        ctx.accounts.no_token_mint.key(),
+        ctx.accounts.yes_token_vault.key(),
+        ctx.accounts.no_token_vault.key(),
+        fee_rate,
+        ctx.bumps.pool,
+    )?;
+
+    emit!(PoolInitializedEvent {
+        market: market.key(),
+        pool: pool.key(),
+        yes_token_mint: pool.yes_token_mint,
+        no_token_mint: pool.no_token_mint,
+        fee_rate,
+        created_at: pool.created_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PoolInitializedEvent {
+    pub market: Pubkey,
+    pub pool: Pubkey,
+    pub yes_token_mint: Pubkey,
+    pub no_token_mint: Pubkey,
+    pub fee_rate: u16,
+    pub created_at: i64,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::{outcome_token_accounts, transfer_tokens};
+
+#[derive(Accounts)]
+pub struct MergePositions<'info> {
+    #[account(
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.has_outcome_tokens() @ ZentroError::OutcomeMintsMissing,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Burns `amount` complete sets of outcome tokens and returns `amount`
+/// collateral. A complete set is always worth one unit, so this works in any
+/// market state. `remaining_accounts` holds, for each outcome in order, its
+/// writable mint followed by the user's token account of it.
+pub fn merge_positions<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MergePositions<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, ZentroError::InvalidAmount);
+
+    let market = &ctx.accounts.market;
+    let user = ctx.accounts.user.key();
+    let sets = outcome_token_accounts(&market.key(), market.outcomes.len(), ctx.remaining_accounts)?;
+    for (_, from) in &sets {
+        require_keys_eq!(from.owner, user, ZentroError::InvalidUserTokenAccount);
+        require!(from.amount >= amount, ZentroError::InsufficientShares);
+    }
+    require!(
+        ctx.accounts.market_vault.amount >= amount,
+        ZentroError::InsufficientVaultBalance
+    );
+
+    for (mint, from) in &sets {
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: mint.to_account_info(),
+                    from: from.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        amount,
+    )?;
+
+    emit!(PositionsMergedEvent {
+        market: market.key(),
+        user,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PositionsMergedEvent {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
@@ -1 +1,87 @@
-I'm sorry. I'm not able to assist with that.
\ No newline at end of file
+pub mod accept_admin;
+pub mod add_liquidity;
+pub mod buy_shares;
+pub mod cancel_disputed_market;
+pub mod cancel_market;
+pub mod cancel_order;
+pub mod cast_resolution_vote;
+pub mod claim_creator_deposit;
+pub mod claim_refund;
+pub mod claim_winnings;
+pub mod consume_events;
+pub mod create_committee;
+pub mod create_market;
+pub mod dispute_resolution;
+pub mod finalize_resolution;
+pub mod initialize_config;
+pub mod initialize_order_book;
+pub mod initialize_outcome_mint;
+pub mod initialize_pool;
+pub mod merge_positions;
+pub mod place_bet;
+pub mod place_limit_order;
+pub mod propose_admin;
+pub mod propose_resolution;
+pub mod redeem;
+pub mod remove_liquidity;
+pub mod resolve_market;
+pub mod resolve_oracle_fallback;
+pub mod resolve_with_oracle;
+pub mod sell_position;
+pub mod sell_shares;
+pub mod set_market_paused;
+pub mod set_oracle_program;
+pub mod set_protocol_paused;
+pub mod set_role;
+pub mod settle_dispute;
+pub mod settle_funds;
+pub mod split_collateral;
+pub mod sweep_market_surplus;
+pub mod update_config;
+pub mod whitelist_market;
+pub mod withdraw_creator_fees;
+pub mod withdraw_protocol_fees;
+
+pub use accept_admin::*;
+pub use add_liquidity::*;
+pub use buy_shares::*;
+pub use cancel_disputed_market::*;
+pub use cancel_market::*;
+pub use cancel_order::*;
+pub use cast_resolution_vote::*;
+pub use claim_creator_deposit::*;
+pub use claim_refund::*;
+pub use claim_winnings::*;
+pub use consume_events::*;
+pub use create_committee::*;
+pub use create_market::*;
+pub use dispute_resolution::*;
+pub use finalize_resolution::*;
+pub use initialize_config::*;
+pub use initialize_order_book::*;
+pub use initialize_outcome_mint::*;
+pub use initialize_pool::*;
+pub use merge_positions::*;
+pub use place_bet::*;
+pub use place_limit_order::*;
+pub use propose_admin::*;
+pub use propose_resolution::*;
+pub use redeem::*;
+pub use remove_liquidity::*;
+pub use resolve_market::*;
+pub use resolve_oracle_fallback::*;
+pub use resolve_with_oracle::*;
+pub use sell_position::*;
+pub use sell_shares::*;
+pub use set_market_paused::*;
+pub use set_oracle_program::*;
+pub use set_protocol_paused::*;
+pub use set_role::*;
+pub use settle_dispute::*;
+pub use settle_funds::*;
+pub use split_collateral::*;
+pub use sweep_market_surplus::*;
+pub use update_config::*;
+pub use whitelist_market::*;
+pub use withdraw_creator_fees::*;
+pub use withdraw_protocol_fees::*;
@@ -1,103 +1,182 @@
-```rust
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
 use crate::state::*;
-use crate::errors::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::lmsr;
+use crate::utils::slippage::{check_deadline, check_max_cost};
+use crate::utils::token::transfer_tokens;
 
 #[derive(Accounts)]
+#[instruction(amount: u64, outcome: u8)]
 pub struct PlaceBet<'info> {
     #[account(
         mut,
-        seeds = [b"market", market.creator.as_ref(), &market.market_id.to_le_bytes()],
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
         bump = market.bump,
-        constraint = market.status == MarketStatus::Active @ ZentroError::MarketNotActive,
-        constraint = market.resolution_time > Clock::get()?.unix_timestamp @ ZentroError::MarketExpired
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = market.end_time > Clock::get()?.unix_timestamp @ ZentroError::MarketExpired
     )]
     pub market: Account<'info, Market>,
 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ZentroError::ProtocolPaused
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         init,
         payer = bettor,
         space = 8 + Bet::INIT_SPACE,
-        seeds = [b"bet", market.key().as_ref(), bettor.key().as_ref(), &market.total_bets.to_le_bytes()],
+        seeds = [BET_SEED, market.key().as_ref(), bettor.key().as_ref(), market.total_bets.to_le_bytes().as_ref()],
         bump
     )]
     pub bet: Account<'info, Bet>,
 
+    #[account(
+        init_if_needed,
+        payer = bettor,
+        space = UserPosition::LEN,
+        seeds = [POSITION_SEED, market.key().as_ref(), bettor.key().as_ref(), &[outcome]],
+        bump
+    )]
+    pub position: Account<'info, UserPosition>,
+
     #[account(mut)]
     pub bettor: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"vault", market.key().as_ref()],
-        bump = market.vault_bump
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount
     )]
-    pub vault: SystemAccount<'info>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        mut,
+        constraint = bettor_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = bettor_token_account.owner == bettor.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub bettor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn place_bet(
     ctx: Context<PlaceBet>,
     amount: u64,
-    prediction: bool,
+    outcome: u8,
+    max_cost: u64,
+    expires_at: Option<i64>,
 ) -> Result<()> {
     let market = &mut ctx.accounts.market;
     let bet = &mut ctx.accounts.bet;
+    let position = &mut ctx.accounts.position;
     let bettor = &ctx.accounts.bettor;
     let vault = &ctx.accounts.vault;
 
-    require!(amount > 0, ZentroError::InvalidBetAmount);
-    require!(amount >= market.min_bet_amount, ZentroError::BetAmountTooLow);
-    require!(amount <= market.max_bet_amount, ZentroError::BetAmountTooHigh);
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
 
-    // Transfer SOL from bettor to vault
-    let transfer_instruction = anchor_lang::system_program::Transfer {
-        from: bettor.to_account_info(),
-        to: vault.to_account_info(),
-    };
-
-    let cpi_context = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        transfer_instruction,
+    require!(amount > 0, ZentroError::InvalidAmount);
+    require!(
+        (outcome as usize) < market.outcomes.len(),
+        ZentroError::InvalidOutcome
     );
 
-    anchor_lang::system_program::transfer(cpi_context, amount)?;
+    // Parimutuel stakes buy one share per unit; LMSR charges the cost-function delta
+    let (shares, cost) = match market.pricing {
+        PricingEngine::Parimutuel => (amount, amount),
+        PricingEngine::Lmsr { liquidity } => (
+            amount,
+            lmsr::cost_to_buy(&market.outcome_shares(), liquidity, outcome as usize, amount)?,
+        ),
+    };
+
+    require!(cost >= market.min_bet_amount, ZentroError::BetAmountTooLow);
+    require!(cost <= market.max_bet_amount, ZentroError::BetAmountTooHigh);
+
+    // Creator and protocol fees are charged on top of the stake, which alone
+    // counts towards the pool and refunds
+    let (creator_fee, protocol_fee) = market.take_trade_fees(cost)?;
+    let total_cost = cost
+        .checked_add(creator_fee)
+        .and_then(|total| total.checked_add(protocol_fee))
+        .ok_or(ZentroError::MathOverflow)?;
+    check_max_cost(total_cost, max_cost)?;
+
+    // Transfer collateral from bettor to vault
+    let received = transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.bettor_token_account.to_account_info(),
+        vault.to_account_info(),
+        bettor.to_account_info(),
+        &[],
+        total_cost,
+    )?;
+
+    // Only the amount that reached the vault is staked. LMSR markets only
+    // accept fee-free mints, so a transfer fee can only shrink a parimutuel
+    // stake, where shares equal cost.
+    let transfer_fee = total_cost - received;
+    let shares = shares.checked_sub(transfer_fee).ok_or(ZentroError::BetAmountTooLow)?;
+    let cost = cost.checked_sub(transfer_fee).ok_or(ZentroError::BetAmountTooLow)?;
 
     // Initialize bet account
     bet.market = market.key();
     bet.bettor = bettor.key();
-    bet.amount = amount;
-    bet.prediction = prediction;
-    bet.timestamp = Clock::get()?.unix_timestamp;
+    bet.amount = cost;
+    bet.shares = shares;
+    bet.outcome = outcome;
+    bet.timestamp = now;
     bet.bump = ctx.bumps.bet;
 
-    // Update market statistics
-    market.total_bets += 1;
-    market.total_volume += amount;
-
-    if prediction {
-        market.yes_volume += amount;
-        market.yes_bets += 1;
+    if position.market == Pubkey::default() {
+        position.set_inner(UserPosition::new(
+            bettor.key(),
+            market.key(),
+            outcome,
+            shares,
+            cost,
+            ctx.bumps.position,
+        )?);
     } else {
-        market.no_volume += amount;
-        market.no_bets += 1;
+        position.add_shares(shares, cost)?;
     }
 
-    // Update market odds
-    let total_volume = market.yes_volume + market.no_volume;
-    if total_volume > 0 {
-        market.yes_odds = (market.yes_volume as f64 / total_volume as f64 * 100.0) as u8;
-        market.no_odds = 100 - market.yes_odds;
-    }
+    // Update market statistics
+    market.total_bets = market.total_bets.checked_add(1)
+        .ok_or(ZentroError::MathOverflow)?;
+    market.total_volume = market.total_volume.checked_add(cost)
+        .ok_or(ZentroError::MathOverflow)?;
+    market.record_deposit(cost)?;
+
+    let market_outcome = &mut market.outcomes[outcome as usize];
+    market_outcome.total_shares = market_outcome.total_shares.checked_add(shares)
+        .ok_or(ZentroError::MathOverflow)?;
+    market_outcome.total_volume = market_outcome.total_volume.checked_add(cost)
+        .ok_or(ZentroError::MathOverflow)?;
+    let outcome_shares = market_outcome.total_shares;
 
     emit!(BetPlacedEvent {
         market: market.key(),
         bettor: bettor.key(),
-        amount,
-        prediction,
+        amount: cost,
+        shares,
+        outcome,
+        creator_fee,
+        protocol_fee,
         timestamp: bet.timestamp,
-        market_odds_yes: market.yes_odds,
-        market_odds_no: market.no_odds,
+        outcome_shares,
+        total_pool: market.total_pool().ok_or(ZentroError::MathOverflow)?,
     });
 
     Ok(())
@@ -108,9 +187,11 @@ pub struct BetPlacedEvent {
     pub market: Pubkey,
     pub bettor: Pubkey,
     pub amount: u64,
-    pub prediction: bool,
+    pub shares: u64,
+    pub outcome: u8,
+    pub creator_fee: u64,
+    pub protocol_fee: u64,
     pub timestamp: i64,
-    pub market_odds_yes: u8,
-    pub market_odds_no: u8,
+    pub outcome_shares: u64,
+    pub total_pool: u64,
 }
-```
\ No newline at end of file
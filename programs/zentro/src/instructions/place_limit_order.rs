@@ -0,0 +1,334 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::slippage::check_deadline;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = market.end_time > Clock::get()?.unix_timestamp @ ZentroError::MarketExpired
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ZentroError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [ORDER_BOOK_SEED, market.key().as_ref(), &[order_book.load()?.outcome]],
+        bump = order_book.load()?.bump,
+        has_one = market @ ZentroError::InvalidMarket,
+    )]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    /// Collects the crank fee for every fill it records
+    #[account(mut, address = order_book.load()?.event_queue)]
+    pub event_queue: AccountLoader<'info, EventQueue>,
+
+    /// Where `consume_events` credits the caller's resting orders
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = OpenOrders::LEN,
+        seeds = [OPEN_ORDERS_SEED, order_book.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub open_orders: Box<Account<'info, OpenOrders>>,
+
+    #[account(
+        mut,
+        address = order_book.load()?.collateral_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = order_book.load()?.share_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = order_book.load()?.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = order_book.load()?.outcome_mint)]
+    pub outcome_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == token_mint.key() @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_outcome_account.mint == outcome_mint.key() @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_outcome_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_outcome_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Buys (`Bid`) or sells (`Ask`) up to `quantity` outcome tokens at `price`
+/// basis points or better. The order first matches resting orders in
+/// price-time priority at their prices, then rests on the book for the rest
+/// with its collateral or tokens escrowed in the book's vaults. Orders worth
+/// less than the book's minimum notional are rejected, and a remainder below
+/// it is left unfilled instead of resting.
+///
+/// The taker settles on the spot. What each maker is owed is pushed to the
+/// event queue for `consume_events`, and the taker pays the book's crank fee
+/// in lamports for every fill. On a full book the order may evict the worst
+/// resting one (see `OrderBook::insert`), whose escrow is queued back to its
+/// owner the same way, for one more crank fee.
+pub fn place_limit_order(
+    ctx: Context<PlaceLimitOrder>,
+    side: Side,
+    price: u16,
+    quantity: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+
+    require!(
+        (OrderBook::MIN_PRICE..=OrderBook::MAX_PRICE).contains(&price),
+        ZentroError::InvalidOrderPrice
+    );
+    require!(quantity > 0, ZentroError::InvalidAmount);
+
+    let order_book_key = ctx.accounts.order_book.key();
+    let user = ctx.accounts.user.key();
+
+    let open_orders = &mut ctx.accounts.open_orders;
+    if open_orders.owner == Pubkey::default() {
+        open_orders.order_book = order_book_key;
+        open_orders.owner = user;
+        open_orders.free_collateral = 0;
+        open_orders.free_shares = 0;
+        open_orders.bump = ctx.bumps.open_orders;
+    }
+
+    let mut order_book = ctx.accounts.order_book.load_mut()?;
+    require!(
+        OrderBook::bid_escrow(quantity, price)? >= order_book.min_order_notional,
+        ZentroError::OrderTooSmall
+    );
+    let mut event_queue = ctx.accounts.event_queue.load_mut()?;
+
+    let (fills, remaining) =
+        order_book.match_order(side, price, quantity, event_queue.free_slots())?;
+    for fill in &fills {
+        event_queue.push(FillEvent {
+            maker: fill.maker,
+            maker_order_id: fill.order_id,
+            quantity: fill.quantity,
+            collateral: fill.collateral,
+            timestamp: now,
+            price: fill.price,
+            maker_side: side.opposite() as u8,
+            evicted: 0,
+            _padding: [0; 4],
+        })?;
+    }
+
+    // A remainder below the minimum is left unfilled rather than rested
+    let resting = if OrderBook::bid_escrow(remaining, price)? >= order_book.min_order_notional {
+        remaining
+    } else {
+        0
+    };
+    let (order_id, evicted) = if resting > 0 {
+        let (order_id, evicted) = order_book.insert(side, user, price, resting, now)?;
+        (Some(order_id), evicted)
+    } else {
+        (None, None)
+    };
+    if let Some((evicted_side, order)) = evicted {
+        let escrow = match evicted_side {
+            Side::Bid => OrderBook::bid_escrow(order.quantity, order.price)?,
+            Side::Ask => 0,
+        };
+        event_queue.push(FillEvent {
+            maker: order.owner,
+            maker_order_id: order.order_id,
+            quantity: order.quantity,
+            collateral: escrow,
+            timestamp: now,
+            price: order.price,
+            maker_side: evicted_side as u8,
+            evicted: 1,
+            _padding: [0; 4],
+        })?;
+    }
+    drop(event_queue);
+
+    let filled: u64 = fills.iter().map(|fill| fill.quantity).sum();
+    let traded: u64 = fills.iter().map(|fill| fill.collateral).sum();
+
+    let (outcome, bump, crank_fee) = (order_book.outcome, order_book.bump, order_book.crank_fee);
+    drop(order_book);
+
+    let market_key = ctx.accounts.market.key();
+    let book_seeds = &[
+        ORDER_BOOK_SEED,
+        market_key.as_ref(),
+        &[outcome],
+        &[bump],
+    ];
+    let book_signer = &[&book_seeds[..]];
+
+    // The taker pays in what the makers are owed plus any escrow for the
+    // remainder, and takes out what the matched orders had escrowed
+    let accounts = &ctx.accounts;
+    let (pay_in, take_out) = match side {
+        Side::Bid => (traded.checked_add(OrderBook::bid_escrow(resting, price)?), filled),
+        Side::Ask => (filled.checked_add(resting), traded),
+    };
+    let pay_in = pay_in.ok_or(ZentroError::MathOverflow)?;
+    let ((in_mint, in_source, in_vault), (out_mint, out_vault, out_destination)) = match side {
+        Side::Bid => (
+            (&accounts.token_mint, &accounts.user_token_account, &accounts.collateral_vault),
+            (&accounts.outcome_mint, &accounts.share_vault, &accounts.user_outcome_account),
+        ),
+        Side::Ask => (
+            (&accounts.outcome_mint, &accounts.user_outcome_account, &accounts.share_vault),
+            (&accounts.token_mint, &accounts.collateral_vault, &accounts.user_token_account),
+        ),
+    };
+
+    if pay_in > 0 {
+        transfer_tokens(
+            &accounts.token_program,
+            in_mint,
+            in_source.to_account_info(),
+            in_vault.to_account_info(),
+            accounts.user.to_account_info(),
+            &[],
+            pay_in,
+        )?;
+    }
+    if take_out > 0 {
+        transfer_tokens(
+            &accounts.token_program,
+            out_mint,
+            out_vault.to_account_info(),
+            out_destination.to_account_info(),
+            accounts.order_book.to_account_info(),
+            book_signer,
+            take_out,
+        )?;
+    }
+
+    let events = fills.len() + evicted.is_some() as usize;
+    let crank_fee = crank_fee
+        .checked_mul(events as u64)
+        .ok_or(ZentroError::MathOverflow)?;
+    if crank_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                Transfer {
+                    from: accounts.user.to_account_info(),
+                    to: accounts.event_queue.to_account_info(),
+                },
+            ),
+            crank_fee,
+        )?;
+    }
+
+    let market = &mut ctx.accounts.market;
+    market.total_volume = market.total_volume.checked_add(traded)
+        .ok_or(ZentroError::MathOverflow)?;
+    // Asks sell tokens whose collateral was recorded when they were bought
+    if side == Side::Bid {
+        market.record_deposit(pay_in)?;
+    }
+
+    for fill in &fills {
+        emit!(OrderFilledEvent {
+            market: market_key,
+            order_book: order_book_key,
+            maker_order_id: fill.order_id,
+            maker: fill.maker,
+            taker: user,
+            taker_side: side,
+            price: fill.price,
+            quantity: fill.quantity,
+            collateral: fill.collateral,
+            timestamp: now,
+        });
+    }
+
+    emit!(LimitOrderPlacedEvent {
+        market: market_key,
+        order_book: order_book_key,
+        owner: user,
+        order_id,
+        side,
+        price,
+        quantity,
+        filled,
+        collateral_traded: traded,
+        evicted_order_id: evicted.map(|(_, order)| order.order_id),
+        crank_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LimitOrderPlacedEvent {
+    pub market: Pubkey,
+    pub order_book: Pubkey,
+    pub owner: Pubkey,
+    /// Id of the resting remainder; `None` if nothing was left to rest
+    pub order_id: Option<u64>,
+    pub side: Side,
+    pub price: u16,
+    pub quantity: u64,
+    /// Outcome tokens matched on arrival
+    pub filled: u64,
+    pub collateral_traded: u64,
+    /// Resting order pushed off a full book to make room for this one
+    pub evicted_order_id: Option<u64>,
+    /// Lamports paid into the event queue for the cranker
+    pub crank_fee: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted once per resting order an incoming order matches. The maker's
+/// side is settled later by `consume_events`.
+#[event]
+pub struct OrderFilledEvent {
+    pub market: Pubkey,
+    pub order_book: Pubkey,
+    pub maker_order_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub taker_side: Side,
+    /// Maker's price, in basis points
+    pub price: u16,
+    pub quantity: u64,
+    pub collateral: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct ProposeResolution<'info> {
+    #[account(
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_open() @ ZentroError::MarketNotActive
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ResolutionProposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, ResolutionProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes the result of an ended market, backed by the market's bond.
+pub fn propose_resolution(ctx: Context<ProposeResolution>, resolution: ResolutionValue) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    let optimistic = market.optimistic.ok_or(ZentroError::OptimisticResolutionDisabled)?;
+    require!(now >= market.end_time, ZentroError::MarketNotEnded);
+    market.validate_resolution(resolution)?;
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.proposer.to_account_info(),
+                to: proposal.to_account_info(),
+            },
+        ),
+        optimistic.bond,
+    )?;
+
+    proposal.market = market.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.resolution = resolution;
+    proposal.bond = optimistic.bond;
+    proposal.proposed_at = now;
+    proposal.challenge_ends_at = now
+        .checked_add(optimistic.challenge_period)
+        .ok_or(ZentroError::MathOverflow)?;
+    proposal.disputer = None;
+    proposal.disputed_at = 0;
+    proposal.voting_ends_at = 0;
+    proposal.votes = Vec::new();
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(ResolutionProposedEvent {
+        market: market.key(),
+        proposer: proposal.proposer,
+        resolution,
+        bond: proposal.bond,
+        challenge_ends_at: proposal.challenge_ends_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResolutionProposedEvent {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub resolution: ResolutionValue,
+    pub bond: u64,
+    pub challenge_ends_at: i64,
+}
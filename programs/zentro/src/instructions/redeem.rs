@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+use crate::utils::pricing::calculate_share_payout;
+
+#[derive(Accounts)]
+#[instruction(amount: u64, outcome: u8)]
+pub struct Redeem<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_resolved() || market.is_cancelled() @ ZentroError::MarketNotResolved
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [OUTCOME_MINT_SEED, market.key().as_ref(), &[outcome]],
+        bump,
+    )]
+    pub outcome_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holds the outcome tokens being redeemed
+    #[account(
+        mut,
+        constraint = user_outcome_account.mint == outcome_mint.key() @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_outcome_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_outcome_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Burns `amount` tokens of `outcome` of a resolved market for their payout
+/// weight in collateral. On a cancelled market each token is worth an even
+/// share of a unit, so one-sided holders get back what a complete set backs.
+pub fn redeem(ctx: Context<Redeem>, amount: u64, outcome: u8) -> Result<()> {
+    require!(amount > 0, ZentroError::InvalidAmount);
+    require!(
+        ctx.accounts.user_outcome_account.amount >= amount,
+        ZentroError::InsufficientShares
+    );
+
+    let weight = if ctx.accounts.market.is_cancelled() {
+        10000 / ctx.accounts.market.outcomes.len() as u64
+    } else {
+        ctx.accounts.market.payout_weight(outcome)?
+    };
+    require!(weight > 0, ZentroError::NotWinningPosition);
+    let payout = calculate_share_payout(amount, weight)?;
+    require!(
+        ctx.accounts.market_vault.amount >= payout,
+        ZentroError::InsufficientVaultBalance
+    );
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.outcome_mint.to_account_info(),
+                from: ctx.accounts.user_outcome_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let market = &ctx.accounts.market;
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        payout,
+    )?;
+
+    let market = &mut ctx.accounts.market;
+    market.total_redeemed = market.total_redeemed.checked_add(payout)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    emit!(RedeemedEvent {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        outcome,
+        amount,
+        payout,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RedeemedEvent {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub outcome: u8,
+    pub amount: u64,
+    pub payout: u64,
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, market.key().as_ref()],
+        bump = pool.bump,
+        has_one = market @ ZentroError::InvalidMarket,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_SEED, market.key().as_ref(), user.key().as_ref()],
+        bump = liquidity_position.bump,
+        constraint = liquidity_position.owner == user.key() @ ZentroError::InvalidUser,
+    )]
+    pub liquidity_position: Box<Account<'info, LiquidityPosition>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = pool.token_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_mint)]
+    pub yes_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.no_token_mint)]
+    pub no_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_vault)]
+    pub yes_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.no_token_vault)]
+    pub no_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives YES tokens that cannot be paired into complete sets
+    #[account(
+        mut,
+        constraint = user_yes_account.mint == pool.yes_token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_yes_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub user_yes_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives NO tokens that cannot be paired into complete sets
+    #[account(
+        mut,
+        constraint = user_no_account.mint == pool.no_token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_no_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub user_no_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RemoveLiquidity<'info> {
+    /// Burns `shares` of the caller's LP position for its part of the pool
+    /// reserves. Swap fees compound into the reserves, so the payout already
+    /// carries the position's share of them. Matched YES/NO pairs are paid
+    /// out as collateral and the rest as outcome tokens; the position is
+    /// closed once empty.
+    pub fn remove_liquidity(&mut self, shares: u64) -> Result<()> {
+        require!(shares > 0, ZentroError::InvalidAmount);
+        require!(
+            shares <= self.liquidity_position.shares,
+            ZentroError::InsufficientShares
+        );
+
+        let (yes_amount, no_amount) = self.pool.remove_liquidity(shares)?;
+
+        // Matching YES/NO pairs are merged back into collateral; whatever is
+        // left of the heavier side is paid out as outcome tokens
+        let collateral_out = yes_amount.min(no_amount);
+        let yes_out = yes_amount - collateral_out;
+        let no_out = no_amount - collateral_out;
+
+        require!(
+            self.market_vault.amount >= collateral_out,
+            ZentroError::InsufficientVaultBalance
+        );
+
+        let market_key = self.market.key();
+        let pool_seeds = &[POOL_SEED, market_key.as_ref(), &[self.pool.bump]];
+        let pool_signer = &[&pool_seeds[..]];
+
+        if collateral_out > 0 {
+            let sets = [
+                (&self.yes_token_mint, &self.yes_token_vault),
+                (&self.no_token_mint, &self.no_token_vault),
+            ];
+            for (mint, vault) in sets {
+                let burn_ctx = CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Burn {
+                        mint: mint.to_account_info(),
+                        from: vault.to_account_info(),
+                        authority: self.pool.to_account_info(),
+                    },
+                    pool_signer,
+                );
+                token_interface::burn(burn_ctx, collateral_out)?;
+            }
+
+            let market_id_bytes = self.market.market_id.to_le_bytes();
+            let market_seeds = &[
+                MARKET_SEED,
+                self.market.authority.as_ref(),
+                market_id_bytes.as_ref(),
+                &[self.market.bump],
+            ];
+            let market_signer = &[&market_seeds[..]];
+
+            transfer_tokens(
+                &self.token_program,
+                &self.token_mint,
+                self.market_vault.to_account_info(),
+                self.user_token_account.to_account_info(),
+                self.market.to_account_info(),
+                market_signer,
+                collateral_out,
+            )?;
+        }
+
+        let leftovers = [
+            (&self.yes_token_mint, &self.yes_token_vault, &self.user_yes_account, yes_out),
+            (&self.no_token_mint, &self.no_token_vault, &self.user_no_account, no_out),
+        ];
+        for (mint, vault, to, amount) in leftovers {
+            if amount == 0 {
+                continue;
+            }
+            transfer_tokens(
+                &self.token_program,
+                mint,
+                vault.to_account_info(),
+                to.to_account_info(),
+                self.pool.to_account_info(),
+                pool_signer,
+                amount,
+            )?;
+        }
+
+        // Update liquidity position
+        self.liquidity_position.shares = self
+            .liquidity_position
+            .shares
+            .checked_sub(shares)
+            .ok_or(ZentroError::MathOverflow)?;
+
+        // Update market state
+        self.market.total_liquidity = self.pool.total_liquidity;
+
+        emit!(LiquidityRemovedEvent {
+            market: self.market.key(),
+            user: self.user.key(),
+            shares,
+            collateral_amount: collateral_out,
+            yes_amount: yes_out,
+            no_amount: no_out,
+            total_liquidity: self.market.total_liquidity,
+        });
+
+        // Return the rent once the provider has fully exited
+        if self.liquidity_position.shares == 0 {
+            self.liquidity_position.close(self.user.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[event]
+pub struct LiquidityRemovedEvent {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub shares: u64,
+    pub collateral_amount: u64,
+    pub yes_amount: u64,
+    pub no_amount: u64,
+    pub total_liquidity: u64,
+}
@@ -1,46 +1,40 @@
-```rust
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::errors::*;
+use crate::error::*;
+use crate::seeds::*;
 
 #[derive(Accounts)]
 pub struct ResolveMarket<'info> {
     #[account(
         mut,
-        has_one = authority @ ZentroError::Unauthorized,
-        constraint = market.status == MarketStatus::Active @ ZentroError::MarketNotActive
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = authority @ ZentroError::UnauthorizedResolution,
+        constraint = market.is_open() @ ZentroError::MarketNotActive,
+        constraint = market.is_manually_resolved() @ ZentroError::ManualResolutionDisabled
     )]
     pub market: Account<'info, Market>,
     
-    #[account(mut)]
     pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
 }
 
 pub fn resolve_market(
     ctx: Context<ResolveMarket>,
-    outcome: u8,
+    resolution: ResolutionValue,
 ) -> Result<()> {
     let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
     
     require!(
-        outcome < market.outcomes.len() as u8,
-        ZentroError::InvalidOutcome
-    );
-    
-    require!(
-        Clock::get()?.unix_timestamp >= market.end_time,
+        now >= market.end_time,
         ZentroError::MarketNotEnded
     );
     
-    market.status = MarketStatus::Resolved;
-    market.winning_outcome = Some(outcome);
-    market.resolved_at = Clock::get()?.unix_timestamp;
+    market.resolve(resolution, now)?;
     
     emit!(MarketResolvedEvent {
         market: market.key(),
-        outcome,
+        resolution,
         resolved_at: market.resolved_at,
     });
     
@@ -50,7 +44,6 @@ pub fn resolve_market(
 #[event]
 pub struct MarketResolvedEvent {
     pub market: Pubkey,
-    pub outcome: u8,
+    pub resolution: ResolutionValue,
     pub resolved_at: i64,
 }
-```
\ No newline at end of file
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use super::resolve_market::MarketResolvedEvent;
+
+#[derive(Accounts)]
+pub struct ResolveOracleFallback<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_open() @ ZentroError::MarketNotActive
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.has_role(Role::OracleManager, &oracle_manager.key()) @ ZentroError::MissingRole
+    )]
+    pub config: Account<'info, Config>,
+
+    pub oracle_manager: Signer<'info>,
+}
+
+/// Lets the oracle manager settle an oracle market by hand once its price
+/// window has closed without anyone calling `resolve_with_oracle`; feeds
+/// overwrite their price, so the window's prices may no longer be readable.
+pub fn resolve_oracle_fallback(
+    ctx: Context<ResolveOracleFallback>,
+    resolution: ResolutionValue,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+
+    let window_end = market.oracle_window_end().ok_or(ZentroError::OracleNotConfigured)?;
+    require!(now > window_end, ZentroError::OracleWindowOpen);
+
+    market.resolve(resolution, now)?;
+
+    emit!(OracleFallbackUsedEvent {
+        market: market.key(),
+        oracle_manager: ctx.accounts.oracle_manager.key(),
+        window_end,
+    });
+
+    emit!(MarketResolvedEvent {
+        market: market.key(),
+        resolution,
+        resolved_at: market.resolved_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleFallbackUsedEvent {
+    pub market: Pubkey,
+    pub oracle_manager: Pubkey,
+    /// Last second an oracle price would have been accepted
+    pub window_end: i64,
+}
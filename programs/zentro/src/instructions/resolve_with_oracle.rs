@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::oracle::load_price_feed;
+use super::resolve_market::MarketResolvedEvent;
+
+#[derive(Accounts)]
+pub struct ResolveWithOracle<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_open() @ ZentroError::MarketNotActive
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.oracle_program != Pubkey::default() @ ZentroError::OracleNotConfigured
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: must be the feed bound at creation and owned by the configured
+    /// oracle program; its layout is checked by `load_price_feed`
+    #[account(owner = config.oracle_program @ ZentroError::InvalidOracleAccount)]
+    pub price_feed: UncheckedAccount<'info>,
+}
+
+pub fn resolve_with_oracle(ctx: Context<ResolveWithOracle>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= market.end_time, ZentroError::MarketNotEnded);
+
+    let oracle = market.oracle.ok_or(ZentroError::OracleNotConfigured)?;
+    require_keys_eq!(
+        ctx.accounts.price_feed.key(),
+        oracle.feed,
+        ZentroError::InvalidOracleAuthority
+    );
+
+    let feed = load_price_feed(&ctx.accounts.price_feed.try_borrow_data()?)?;
+    feed.validate(market.end_time, oracle.max_staleness, oracle.max_confidence_bps)?;
+
+    let resolution = market.oracle_resolution(&feed)?;
+    market.resolve(resolution, now)?;
+
+    emit!(OraclePriceUsedEvent {
+        market: market.key(),
+        price_feed: oracle.feed,
+        price: feed.price,
+        conf: feed.conf,
+        expo: feed.expo,
+        publish_time: feed.publish_time,
+    });
+
+    emit!(MarketResolvedEvent {
+        market: market.key(),
+        resolution,
+        resolved_at: market.resolved_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OraclePriceUsedEvent {
+    pub market: Pubkey,
+    pub price_feed: Pubkey,
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
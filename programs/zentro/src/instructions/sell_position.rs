@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::lmsr;
+use crate::utils::pricing::calculate_parimutuel_exit;
+use crate::utils::slippage::{check_deadline, check_min_amount_out};
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct SellPosition<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = market.end_time > Clock::get()?.unix_timestamp @ ZentroError::MarketExpired
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ZentroError::ProtocolPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            POSITION_SEED,
+            market.key().as_ref(),
+            user.key().as_ref(),
+            &[position.outcome]
+        ],
+        bump = position.bump,
+        constraint = position.market == market.key() @ ZentroError::InvalidMarket,
+        constraint = position.user == user.key() @ ZentroError::InvalidUser
+    )]
+    pub position: Account<'info, UserPosition>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Sells `shares` of a position back to the market before resolution. LMSR
+/// markets pay the cost-function refund; parimutuel markets return the
+/// outcome's average stake per share. Creator and protocol fees come out of
+/// the proceeds, and the result against the shares' cost basis is booked as
+/// realized PnL. On LMSR markets that result is also settled against the
+/// subsidy, which the market maker pays profits from: a sale whose profit
+/// exceeds what is left of the subsidy fails with `ExitExceedsSubsidy`, and
+/// the seller must wait for resolution instead. Fails unless at least
+/// `min_amount_out` is paid before `expires_at`.
+pub fn sell_position(
+    ctx: Context<SellPosition>,
+    shares: u64,
+    min_amount_out: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+
+    require!(shares > 0, ZentroError::InvalidAmount);
+    require!(shares <= position.shares, ZentroError::InsufficientShares);
+
+    let outcome = position.outcome;
+    let market_outcome = market.outcome(outcome).ok_or(ZentroError::InvalidOutcome)?;
+    let proceeds = match market.pricing {
+        PricingEngine::Parimutuel => calculate_parimutuel_exit(
+            shares,
+            market_outcome.total_shares,
+            market_outcome.total_volume,
+        )?,
+        PricingEngine::Lmsr { liquidity } => {
+            lmsr::cost_to_sell(&market.outcome_shares(), liquidity, outcome as usize, shares)?
+        }
+    };
+
+    let (creator_fee, protocol_fee) = market.take_trade_fees(proceeds)?;
+    let payout = proceeds
+        .checked_sub(creator_fee)
+        .and_then(|net| net.checked_sub(protocol_fee))
+        .ok_or(ZentroError::MathOverflow)?;
+    check_min_amount_out(payout, min_amount_out)?;
+    require!(
+        ctx.accounts.vault.amount >= payout,
+        ZentroError::InsufficientVaultBalance
+    );
+
+    let invested = position.total_invested;
+    let realized_pnl = position.sell_shares(shares, payout)?;
+    if let PricingEngine::Lmsr { .. } = market.pricing {
+        let cost_basis = invested.checked_sub(position.total_invested)
+            .ok_or(ZentroError::MathOverflow)?;
+        market.book_lmsr_exit(proceeds, cost_basis)?;
+    }
+
+    // Parimutuel stakes leave the pool; LMSR volume is only a statistic
+    let parimutuel = market.pricing == PricingEngine::Parimutuel;
+    let market_outcome = &mut market.outcomes[outcome as usize];
+    market_outcome.total_shares = market_outcome.total_shares.checked_sub(shares)
+        .ok_or(ZentroError::MathOverflow)?;
+    if parimutuel {
+        market_outcome.total_volume = market_outcome.total_volume.checked_sub(proceeds)
+            .ok_or(ZentroError::MathOverflow)?;
+    }
+    market.total_volume = market.total_volume.checked_add(proceeds)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        payout,
+    )?;
+
+    emit!(PositionSoldEvent {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        position: position.key(),
+        outcome,
+        shares,
+        payout,
+        creator_fee,
+        protocol_fee,
+        realized_pnl,
+        remaining_shares: position.shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PositionSoldEvent {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub position: Pubkey,
+    pub outcome: u8,
+    pub shares: u64,
+    /// Collateral paid out after creator and protocol fees
+    pub payout: u64,
+    pub creator_fee: u64,
+    pub protocol_fee: u64,
+    /// PnL of this sale against the shares' cost basis
+    pub realized_pnl: i64,
+    pub remaining_shares: u64,
+    pub timestamp: i64,
+}
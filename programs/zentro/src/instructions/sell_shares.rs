@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::slippage::{check_deadline, check_min_amount_out};
+use crate::utils::token::transfer_tokens;
+use super::buy_shares::TradeEvent;
+
+#[derive(Accounts)]
+#[instruction(shares: u64, is_yes: bool)]
+pub struct SellShares<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = market.end_time > Clock::get()?.unix_timestamp @ ZentroError::MarketExpired
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ZentroError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, market.key().as_ref()],
+        bump = pool.bump,
+        has_one = market @ ZentroError::InvalidMarket,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        mut,
+        address = pool.token_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_mint)]
+    pub yes_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.no_token_mint)]
+    pub no_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.yes_token_vault)]
+    pub yes_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = pool.no_token_vault)]
+    pub no_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Holds the YES or NO tokens being sold
+    #[account(
+        mut,
+        constraint = user_outcome_account.mint == pool.outcome_mint(is_yes) @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_outcome_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_outcome_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Sells `shares` YES (`is_yes`) or NO tokens for collateral. The pool swaps
+/// part of them for the opposite side and redeems the resulting complete sets
+/// from the market vault, less the creator and protocol fees. Fails unless at
+/// least `min_collateral_out` is paid out before `expires_at`.
+pub fn sell_shares(
+    ctx: Context<SellShares>,
+    shares: u64,
+    is_yes: bool,
+    min_collateral_out: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    check_deadline(expires_at, now)?;
+
+    require!(shares > 0, ZentroError::InvalidAmount);
+    require!(
+        ctx.accounts.user_outcome_account.amount >= shares,
+        ZentroError::InsufficientShares
+    );
+
+    let pool = &mut ctx.accounts.pool;
+
+    let (swap_in, collateral_out) = pool.get_sell_amounts(shares, is_yes)?;
+
+    // Creator and protocol cuts come out of the payout and stay in the vault
+    let (creator_fee, protocol_fee) = ctx.accounts.market.take_trade_fees(collateral_out)?;
+    let payout = collateral_out
+        .checked_sub(creator_fee)
+        .and_then(|net| net.checked_sub(protocol_fee))
+        .ok_or(ZentroError::MathOverflow)?;
+    check_min_amount_out(payout, min_collateral_out)?;
+
+    let fee = pool.swap_fee(swap_in)?;
+    pool.execute_sell(shares, swap_in, collateral_out, is_yes)?;
+
+    require!(
+        ctx.accounts.market_vault.amount >= collateral_out,
+        ZentroError::InsufficientVaultBalance
+    );
+
+    // Move the sold tokens into the pool
+    let (outcome_mint, pool_vault) = if is_yes {
+        (&ctx.accounts.yes_token_mint, &ctx.accounts.yes_token_vault)
+    } else {
+        (&ctx.accounts.no_token_mint, &ctx.accounts.no_token_vault)
+    };
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        outcome_mint,
+        ctx.accounts.user_outcome_account.to_account_info(),
+        pool_vault.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        &[],
+        shares,
+    )?;
+
+    // Burn the complete sets backing the payout
+    let market_key = ctx.accounts.market.key();
+    let pool_seeds = &[POOL_SEED, market_key.as_ref(), &[pool.bump]];
+    let pool_signer = &[&pool_seeds[..]];
+
+    let sets = [
+        (&ctx.accounts.yes_token_mint, &ctx.accounts.yes_token_vault),
+        (&ctx.accounts.no_token_mint, &ctx.accounts.no_token_vault),
+    ];
+    for (mint, vault) in sets {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: mint.to_account_info(),
+                    from: vault.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                pool_signer,
+            ),
+            collateral_out,
+        )?;
+    }
+
+    // Release their collateral to the seller
+    let market = &ctx.accounts.market;
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        payout,
+    )?;
+
+    let market = &mut ctx.accounts.market;
+    market.total_volume = market.total_volume.checked_add(collateral_out)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    emit!(TradeEvent {
+        market: market.key(),
+        pool: pool.key(),
+        trader: ctx.accounts.user.key(),
+        is_buy: false,
+        is_yes,
+        collateral_amount: payout,
+        shares,
+        fee,
+        creator_fee,
+        protocol_fee,
+        yes_reserves: pool.yes_reserves,
+        no_reserves: pool.no_reserves,
+        yes_price: pool.get_current_price()?,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct SetMarketPaused<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.has_role(Role::Pauser, &pauser.key()) @ ZentroError::MissingRole
+    )]
+    pub config: Account<'info, Config>,
+
+    pub pauser: Signer<'info>,
+}
+
+/// Pauses or resumes trading on a single market. `reason` is an off-chain
+/// code recorded in the event for monitoring.
+pub fn set_market_paused(ctx: Context<SetMarketPaused>, paused: bool, reason: u16) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    market.set_paused(paused)?;
+
+    emit!(MarketPauseChangedEvent {
+        market: market.key(),
+        paused,
+        reason,
+        pauser: ctx.accounts.pauser.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketPauseChangedEvent {
+    pub market: Pubkey,
+    pub paused: bool,
+    pub reason: u16,
+    pub pauser: Pubkey,
+    pub timestamp: i64,
+}
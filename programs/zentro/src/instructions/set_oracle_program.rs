@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct SetOracleProgram<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.has_role(Role::OracleManager, &oracle_manager.key()) @ ZentroError::MissingRole
+    )]
+    pub config: Account<'info, Config>,
+
+    pub oracle_manager: Signer<'info>,
+}
+
+/// Sets the program that must own the price feeds oracle markets resolve
+/// from, e.g. the Pyth oracle program of the cluster.
+pub fn set_oracle_program(ctx: Context<SetOracleProgram>, oracle_program: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let previous_program = config.oracle_program;
+    config.oracle_program = oracle_program;
+
+    emit!(OracleProgramSetEvent {
+        previous_program,
+        new_program: oracle_program,
+        oracle_manager: ctx.accounts.oracle_manager.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleProgramSetEvent {
+    pub previous_program: Pubkey,
+    pub new_program: Pubkey,
+    pub oracle_manager: Pubkey,
+}
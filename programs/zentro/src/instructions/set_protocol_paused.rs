@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct SetProtocolPaused<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.has_role(Role::Pauser, &pauser.key()) @ ZentroError::MissingRole
+    )]
+    pub config: Account<'info, Config>,
+
+    pub pauser: Signer<'info>,
+}
+
+/// Flips the protocol-wide circuit breaker. `reason` is an off-chain code
+/// recorded in the event for monitoring.
+pub fn set_protocol_paused(ctx: Context<SetProtocolPaused>, paused: bool, reason: u16) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.paused = paused;
+
+    emit!(ProtocolPauseChangedEvent {
+        paused,
+        reason,
+        pauser: ctx.accounts.pauser.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProtocolPauseChangedEvent {
+    pub paused: bool,
+    pub reason: u16,
+    pub pauser: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ ZentroError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn set_role(ctx: Context<SetRole>, role: Role, holder: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    let previous_holder = config.set_role(role, holder);
+
+    emit!(RoleChangedEvent {
+        role,
+        previous_holder,
+        new_holder: holder,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoleChangedEvent {
+    pub role: Role,
+    pub previous_holder: Pubkey,
+    pub new_holder: Pubkey,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use super::resolve_market::MarketResolvedEvent;
+
+#[derive(Accounts)]
+pub struct SettleDispute<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Disputed @ ZentroError::MarketNotDisputed
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Closed to the winning party, which pays out both bonds
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, market.key().as_ref()],
+        bump = proposal.bump,
+        close = winner
+    )]
+    pub proposal: Account<'info, ResolutionProposal>,
+
+    #[account(
+        constraint = market.optimistic.is_some_and(|optimistic| optimistic.committee == committee.key())
+            @ ZentroError::CommitteeMismatch
+    )]
+    pub committee: Account<'info, ResolverCommittee>,
+
+    /// CHECK: must be the proposer if the committee upheld the proposal and
+    /// the disputer otherwise; checked in the handler, only receives lamports
+    #[account(mut)]
+    pub winner: UncheckedAccount<'info>,
+}
+
+/// Settles a disputed market once the committee threshold is reached. The
+/// side the committee agrees with recovers its bond and takes the loser's.
+/// Anyone may call it.
+pub fn settle_dispute(ctx: Context<SettleDispute>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let proposal = &ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    let resolution = proposal
+        .committee_decision(ctx.accounts.committee.threshold)
+        .ok_or(ZentroError::CommitteeThresholdNotReached)?;
+
+    let disputer = proposal.disputer.ok_or(ZentroError::MarketNotDisputed)?;
+    let (winner, loser) = if resolution == proposal.resolution {
+        (proposal.proposer, disputer)
+    } else {
+        (disputer, proposal.proposer)
+    };
+    require_keys_eq!(ctx.accounts.winner.key(), winner, ZentroError::InvalidUser);
+
+    market.resolve(resolution, now)?;
+
+    emit!(DisputeSettledEvent {
+        market: market.key(),
+        resolution,
+        winner,
+        loser,
+        bond_slashed: proposal.bond,
+        votes_for_resolution: proposal.votes_for(resolution) as u8,
+    });
+
+    emit!(MarketResolvedEvent {
+        market: market.key(),
+        resolution,
+        resolved_at: market.resolved_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeSettledEvent {
+    pub market: Pubkey,
+    pub resolution: ResolutionValue,
+    pub winner: Pubkey,
+    pub loser: Pubkey,
+    /// Loser's bond paid to the winner on top of their own
+    pub bond_slashed: u64,
+    pub votes_for_resolution: u8,
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct SettleFunds<'info> {
+    #[account(
+        seeds = [ORDER_BOOK_SEED, order_book.load()?.market.as_ref(), &[order_book.load()?.outcome]],
+        bump = order_book.load()?.bump,
+    )]
+    pub order_book: AccountLoader<'info, OrderBook>,
+
+    #[account(
+        mut,
+        seeds = [OPEN_ORDERS_SEED, order_book.key().as_ref(), user.key().as_ref()],
+        bump = open_orders.bump,
+    )]
+    pub open_orders: Box<Account<'info, OpenOrders>>,
+
+    #[account(
+        mut,
+        address = order_book.load()?.collateral_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = order_book.load()?.share_vault @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub share_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = order_book.load()?.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = order_book.load()?.outcome_mint)]
+    pub outcome_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == token_mint.key() @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_outcome_account.mint == outcome_mint.key() @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_outcome_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_outcome_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Pays out the collateral and outcome tokens `consume_events` has credited
+/// to the caller's filled orders. Works in any market state.
+pub fn settle_funds(ctx: Context<SettleFunds>) -> Result<()> {
+    let open_orders = &mut ctx.accounts.open_orders;
+    let collateral = std::mem::take(&mut open_orders.free_collateral);
+    let shares = std::mem::take(&mut open_orders.free_shares);
+    require!(collateral > 0 || shares > 0, ZentroError::NoFundsToSettle);
+
+    let order_book = ctx.accounts.order_book.load()?;
+    let (market_key, outcome, bump) = (order_book.market, order_book.outcome, order_book.bump);
+    drop(order_book);
+
+    let book_seeds = &[
+        ORDER_BOOK_SEED,
+        market_key.as_ref(),
+        &[outcome],
+        &[bump],
+    ];
+    let book_signer = &[&book_seeds[..]];
+
+    let payouts = [
+        (&ctx.accounts.token_mint, &ctx.accounts.collateral_vault, &ctx.accounts.user_token_account, collateral),
+        (&ctx.accounts.outcome_mint, &ctx.accounts.share_vault, &ctx.accounts.user_outcome_account, shares),
+    ];
+    for (mint, vault, destination, amount) in payouts {
+        if amount > 0 {
+            transfer_tokens(
+                &ctx.accounts.token_program,
+                mint,
+                vault.to_account_info(),
+                destination.to_account_info(),
+                ctx.accounts.order_book.to_account_info(),
+                book_signer,
+                amount,
+            )?;
+        }
+    }
+
+    emit!(FundsSettledEvent {
+        order_book: ctx.accounts.order_book.key(),
+        owner: ctx.accounts.user.key(),
+        collateral,
+        shares,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct FundsSettledEvent {
+    pub order_book: Pubkey,
+    pub owner: Pubkey,
+    pub collateral: u64,
+    pub shares: u64,
+}
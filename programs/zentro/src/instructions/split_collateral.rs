@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::{outcome_token_accounts, transfer_tokens};
+
+#[derive(Accounts)]
+pub struct SplitCollateral<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.is_active() @ ZentroError::MarketNotActive,
+        constraint = market.has_outcome_tokens() @ ZentroError::OutcomeMintsMissing,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ ZentroError::ProtocolPaused
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ZentroError::InvalidUserTokenAccount
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub user: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Locks `amount` collateral in the market vault and mints as much of every
+/// outcome token as arrived there. `remaining_accounts` holds, for each
+/// outcome in order, its writable mint followed by the token account that
+/// receives it; the tokens may be sent to any account.
+pub fn split_collateral<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SplitCollateral<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, ZentroError::InvalidAmount);
+
+    let market = &ctx.accounts.market;
+    let sets = outcome_token_accounts(&market.key(), market.outcomes.len(), ctx.remaining_accounts)?;
+
+    // Sets are minted for what reaches the vault after any transfer fee
+    let amount = transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        &[],
+        amount,
+    )?;
+    require!(amount > 0, ZentroError::InvalidAmount);
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    for (mint, to) in &sets {
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: market.to_account_info(),
+                },
+                market_signer,
+            ),
+            amount,
+        )?;
+    }
+
+    ctx.accounts.market.record_deposit(amount)?;
+
+    emit!(CollateralSplitEvent {
+        market: ctx.accounts.market.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CollateralSplitEvent {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
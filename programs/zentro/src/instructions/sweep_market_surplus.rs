@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct SweepMarketSurplus<'info> {
+    #[account(
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = authority @ ZentroError::Unauthorized,
+        constraint = market.is_resolved() @ ZentroError::MarketNotResolved,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = recipient.owner == authority.key() @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub recipient: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Supply of every outcome token, read from the outcome mints passed in
+/// `accounts` in outcome order; empty for a market without outcome tokens.
+fn outcome_token_supply<'info>(
+    market: &Account<'info, Market>,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<u64>> {
+    if !market.has_outcome_tokens() {
+        return Ok(Vec::new());
+    }
+    require!(accounts.len() == market.outcomes.len(), ZentroError::InvalidOutcomeMint);
+
+    accounts
+        .iter()
+        .zip(0u8..)
+        .map(|(account, outcome)| {
+            let (expected, _) = find_outcome_mint_address(&market.key(), outcome);
+            require_keys_eq!(account.key(), expected, ZentroError::InvalidOutcomeMint);
+            Ok(InterfaceAccount::<Mint>::try_from(account)?.supply)
+        })
+        .collect()
+}
+
+/// Pays the creator of a resolved market what its vault holds beyond the
+/// claims still outstanding and the accrued trade fees: the unspent LMSR
+/// subsidy, an unpaid proposer reward and rounding dust. Can be called again
+/// as claims come in; it never touches collateral a holder can still claim.
+/// Once the market has outcome mints, every one of them is passed in
+/// `remaining_accounts` in outcome order so their supply stays reserved.
+pub fn sweep_market_surplus<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SweepMarketSurplus<'info>>,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+
+    let token_supply = outcome_token_supply(market, ctx.remaining_accounts)?;
+    let reserved = market
+        .outstanding_claims(&token_supply)?
+        .checked_add(market.creator_fees_accrued)
+        .and_then(|reserved| reserved.checked_add(market.protocol_fees_accrued))
+        .ok_or(ZentroError::MathOverflow)?;
+    let amount = ctx.accounts.market_vault.amount.saturating_sub(reserved);
+    require!(amount > 0, ZentroError::NoSurplusToSweep);
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        amount,
+    )?;
+
+    emit!(MarketSurplusSweptEvent {
+        market: market.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        reserved,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketSurplusSweptEvent {
+    pub market: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    /// Collateral left in the vault for outstanding claims and fees
+    pub reserved: u64,
+}
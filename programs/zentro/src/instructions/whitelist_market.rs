@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+
+#[derive(Accounts)]
+pub struct WhitelistMarket<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.status == MarketStatus::Draft @ ZentroError::MarketNotActive
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.has_role(Role::MarketWhitelister, &whitelister.key()) @ ZentroError::MissingRole
+    )]
+    pub config: Account<'info, Config>,
+
+    pub whitelister: Signer<'info>,
+}
+
+/// Opens a draft market for trading.
+pub fn whitelist_market(ctx: Context<WhitelistMarket>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    market.status = MarketStatus::Active;
+
+    emit!(MarketWhitelistedEvent {
+        market: market.key(),
+        whitelister: ctx.accounts.whitelister.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MarketWhitelistedEvent {
+    pub market: Pubkey,
+    pub whitelister: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct WithdrawCreatorFees<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        has_one = authority @ ZentroError::Unauthorized,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = recipient.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = recipient.owner == authority.key() @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub recipient: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Pays the market creator every trade fee accrued so far.
+pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    let amount = market.creator_fees_accrued;
+    require!(amount > 0, ZentroError::NoFeesToWithdraw);
+    require!(
+        ctx.accounts.market_vault.amount >= amount,
+        ZentroError::InsufficientVaultBalance
+    );
+    market.creator_fees_accrued = 0;
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        amount,
+    )?;
+
+    emit!(CreatorFeesWithdrawnEvent {
+        market: market.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CreatorFeesWithdrawnEvent {
+    pub market: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
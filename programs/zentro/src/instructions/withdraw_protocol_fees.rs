@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount};
+
+use crate::state::*;
+use crate::error::*;
+use crate::seeds::*;
+use crate::utils::token::transfer_tokens;
+
+#[derive(Accounts)]
+pub struct WithdrawProtocolFees<'info> {
+    #[account(
+        mut,
+        seeds = [MARKET_SEED, market.authority.as_ref(), market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.has_role(Role::FeeCollector, &fee_collector.key()) @ ZentroError::MissingRole
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, market.key().as_ref()],
+        bump = market.vault_bump,
+        constraint = market_vault.mint == market.token_mint @ ZentroError::InvalidVaultTokenAccount,
+    )]
+    pub market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = market.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Treasury's token account for the market's collateral
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == market.token_mint @ ZentroError::InvalidUserTokenAccount,
+        constraint = treasury_token_account.owner == config.treasury @ ZentroError::InvalidUserTokenAccount,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub fee_collector: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Sweeps the protocol's accrued trade fees from a market into the treasury.
+pub fn withdraw_protocol_fees(ctx: Context<WithdrawProtocolFees>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    let amount = market.protocol_fees_accrued;
+    require!(amount > 0, ZentroError::NoFeesToWithdraw);
+    require!(
+        ctx.accounts.market_vault.amount >= amount,
+        ZentroError::InsufficientVaultBalance
+    );
+    market.protocol_fees_accrued = 0;
+
+    let market_id_bytes = market.market_id.to_le_bytes();
+    let market_seeds = &[
+        MARKET_SEED,
+        market.authority.as_ref(),
+        market_id_bytes.as_ref(),
+        &[market.bump],
+    ];
+    let market_signer = &[&market_seeds[..]];
+
+    transfer_tokens(
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_mint,
+        ctx.accounts.market_vault.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        market.to_account_info(),
+        market_signer,
+        amount,
+    )?;
+
+    emit!(ProtocolFeesWithdrawnEvent {
+        market: market.key(),
+        fee_collector: ctx.accounts.fee_collector.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProtocolFeesWithdrawnEvent {
+    pub market: Pubkey,
+    pub fee_collector: Pubkey,
+    pub amount: u64,
+}
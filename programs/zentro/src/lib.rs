@@ -1 +1,279 @@
-I'm sorry. I'm not able to assist with that.
\ No newline at end of file
+use anchor_lang::prelude::*;
+
+pub mod error;
+#[cfg(test)]
+mod fixtures;
+pub mod instructions;
+pub mod seeds;
+pub mod state;
+pub mod utils;
+
+pub use error::*;
+use instructions::*;
+pub use state::*;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[program]
+pub mod zentro {
+    use super::*;
+
+    /// Creates the protocol `Config`; callable once, by the upgrade authority.
+    pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx, params)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        instructions::update_config::update_config(ctx, params)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+
+    pub fn set_role(ctx: Context<SetRole>, role: Role, holder: Pubkey) -> Result<()> {
+        instructions::set_role::set_role(ctx, role, holder)
+    }
+
+    pub fn set_protocol_paused(ctx: Context<SetProtocolPaused>, paused: bool, reason: u16) -> Result<()> {
+        instructions::set_protocol_paused::set_protocol_paused(ctx, paused, reason)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_market(
+        ctx: Context<CreateMarket>,
+        market_id: u64,
+        title: String,
+        description: String,
+        end_time: i64,
+        category: String,
+        kind: MarketKind,
+        pricing: PricingEngine,
+        outcomes: Vec<String>,
+        min_bet_amount: u64,
+        max_bet_amount: u64,
+        oracle: Option<OracleConfig>,
+        optimistic: Option<OptimisticConfig>,
+        creator_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::create_market::create_market(
+            ctx,
+            market_id,
+            title,
+            description,
+            end_time,
+            category,
+            kind,
+            pricing,
+            outcomes,
+            min_bet_amount,
+            max_bet_amount,
+            oracle,
+            optimistic,
+            creator_fee_bps,
+        )
+    }
+
+    pub fn whitelist_market(ctx: Context<WhitelistMarket>) -> Result<()> {
+        instructions::whitelist_market::whitelist_market(ctx)
+    }
+
+    pub fn set_market_paused(ctx: Context<SetMarketPaused>, paused: bool, reason: u16) -> Result<()> {
+        instructions::set_market_paused::set_market_paused(ctx, paused, reason)
+    }
+
+    /// `amount` is the stake for parimutuel markets and the number of shares
+    /// to buy for LMSR markets; either way the collateral charged may not
+    /// exceed `max_cost`.
+    pub fn place_bet(
+        ctx: Context<PlaceBet>,
+        amount: u64,
+        outcome: u8,
+        max_cost: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::place_bet::place_bet(ctx, amount, outcome, max_cost, expires_at)
+    }
+
+    pub fn sell_position(
+        ctx: Context<SellPosition>,
+        shares: u64,
+        min_amount_out: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::sell_position::sell_position(ctx, shares, min_amount_out, expires_at)
+    }
+
+    pub fn initialize_order_book(ctx: Context<InitializeOrderBook>, outcome: u8) -> Result<()> {
+        instructions::initialize_order_book::initialize_order_book(ctx, outcome)
+    }
+
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        side: Side,
+        price: u16,
+        quantity: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::place_limit_order::place_limit_order(ctx, side, price, quantity, expires_at)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u64) -> Result<()> {
+        instructions::cancel_order::cancel_order(ctx, order_id)
+    }
+
+    pub fn consume_events<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConsumeEvents<'info>>,
+        limit: u16,
+    ) -> Result<()> {
+        instructions::consume_events::consume_events(ctx, limit)
+    }
+
+    pub fn settle_funds(ctx: Context<SettleFunds>) -> Result<()> {
+        instructions::settle_funds::settle_funds(ctx)
+    }
+
+    pub fn resolve_market(ctx: Context<ResolveMarket>, resolution: ResolutionValue) -> Result<()> {
+        instructions::resolve_market::resolve_market(ctx, resolution)
+    }
+
+    /// Permissionless settlement of oracle-bound markets.
+    pub fn resolve_with_oracle(ctx: Context<ResolveWithOracle>) -> Result<()> {
+        instructions::resolve_with_oracle::resolve_with_oracle(ctx)
+    }
+
+    pub fn resolve_oracle_fallback(
+        ctx: Context<ResolveOracleFallback>,
+        resolution: ResolutionValue,
+    ) -> Result<()> {
+        instructions::resolve_oracle_fallback::resolve_oracle_fallback(ctx, resolution)
+    }
+
+    pub fn set_oracle_program(ctx: Context<SetOracleProgram>, oracle_program: Pubkey) -> Result<()> {
+        instructions::set_oracle_program::set_oracle_program(ctx, oracle_program)
+    }
+
+    pub fn propose_resolution(ctx: Context<ProposeResolution>, resolution: ResolutionValue) -> Result<()> {
+        instructions::propose_resolution::propose_resolution(ctx, resolution)
+    }
+
+    pub fn dispute_resolution(ctx: Context<DisputeResolution>) -> Result<()> {
+        instructions::dispute_resolution::dispute_resolution(ctx)
+    }
+
+    /// Permissionless settlement of an unchallenged proposal.
+    pub fn finalize_resolution(ctx: Context<FinalizeResolution>) -> Result<()> {
+        instructions::finalize_resolution::finalize_resolution(ctx)
+    }
+
+    pub fn create_committee(
+        ctx: Context<CreateCommittee>,
+        committee_id: u64,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_committee::create_committee(ctx, committee_id, members, threshold)
+    }
+
+    pub fn cast_resolution_vote(ctx: Context<CastResolutionVote>, resolution: ResolutionValue) -> Result<()> {
+        instructions::cast_resolution_vote::cast_resolution_vote(ctx, resolution)
+    }
+
+    /// Permissionless settlement of a dispute the committee has decided.
+    pub fn settle_dispute(ctx: Context<SettleDispute>) -> Result<()> {
+        instructions::settle_dispute::settle_dispute(ctx)
+    }
+
+    pub fn cancel_disputed_market(ctx: Context<CancelDisputedMarket>) -> Result<()> {
+        instructions::cancel_disputed_market::cancel_disputed_market(ctx)
+    }
+
+    pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
+        instructions::claim_winnings::claim_winnings(ctx)
+    }
+
+    pub fn claim_creator_deposit(ctx: Context<ClaimCreatorDeposit>) -> Result<()> {
+        instructions::claim_creator_deposit::claim_creator_deposit(ctx)
+    }
+
+    pub fn cancel_market(ctx: Context<CancelMarket>) -> Result<()> {
+        instructions::cancel_market::cancel_market(ctx)
+    }
+
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        instructions::claim_refund::claim_refund(ctx)
+    }
+
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount: u64) -> Result<()> {
+        ctx.accounts.add_liquidity(amount, &ctx.bumps)
+    }
+
+    pub fn remove_liquidity(ctx: Context<RemoveLiquidity>, shares: u64) -> Result<()> {
+        ctx.accounts.remove_liquidity(shares)
+    }
+
+    /// Opens the YES/NO constant-product pool of a two-outcome market.
+    pub fn initialize_pool(ctx: Context<InitializePool>, fee_rate: u16) -> Result<()> {
+        instructions::initialize_pool::initialize_pool(ctx, fee_rate)
+    }
+
+    pub fn buy_shares(
+        ctx: Context<BuyShares>,
+        amount: u64,
+        is_yes: bool,
+        min_shares_out: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::buy_shares::buy_shares(ctx, amount, is_yes, min_shares_out, expires_at)
+    }
+
+    pub fn sell_shares(
+        ctx: Context<SellShares>,
+        shares: u64,
+        is_yes: bool,
+        min_collateral_out: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::sell_shares::sell_shares(ctx, shares, is_yes, min_collateral_out, expires_at)
+    }
+
+    pub fn initialize_outcome_mint(ctx: Context<InitializeOutcomeMint>, outcome: u8) -> Result<()> {
+        instructions::initialize_outcome_mint::initialize_outcome_mint(ctx, outcome)
+    }
+
+    pub fn split_collateral<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SplitCollateral<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::split_collateral::split_collateral(ctx, amount)
+    }
+
+    pub fn merge_positions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MergePositions<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::merge_positions::merge_positions(ctx, amount)
+    }
+
+    pub fn redeem(ctx: Context<Redeem>, amount: u64, outcome: u8) -> Result<()> {
+        instructions::redeem::redeem(ctx, amount, outcome)
+    }
+
+    pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+        instructions::withdraw_creator_fees::withdraw_creator_fees(ctx)
+    }
+
+    pub fn sweep_market_surplus<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepMarketSurplus<'info>>,
+    ) -> Result<()> {
+        instructions::sweep_market_surplus::sweep_market_surplus(ctx)
+    }
+
+    pub fn withdraw_protocol_fees(ctx: Context<WithdrawProtocolFees>) -> Result<()> {
+        instructions::withdraw_protocol_fees::withdraw_protocol_fees(ctx)
+    }
+}
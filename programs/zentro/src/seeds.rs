@@ -0,0 +1,154 @@
+//! PDA seed prefixes and address derivation shared by the program and
+//! off-chain clients. The `#[constant]` prefixes are also emitted in the IDL.
+
+use anchor_lang::prelude::*;
+
+/// `[CONFIG_SEED]`
+#[constant]
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// `[MARKET_SEED, authority, market_id.to_le_bytes()]`
+#[constant]
+pub const MARKET_SEED: &[u8] = b"market";
+
+/// `[VAULT_SEED, market]`
+#[constant]
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// `[POSITION_SEED, market, user, [outcome]]`
+#[constant]
+pub const POSITION_SEED: &[u8] = b"position";
+
+/// `[BET_SEED, market, bettor, bet_index.to_le_bytes()]`
+#[constant]
+pub const BET_SEED: &[u8] = b"bet";
+
+/// `[LIQUIDITY_SEED, market, owner]`
+#[constant]
+pub const LIQUIDITY_SEED: &[u8] = b"liquidity";
+
+/// `[POOL_SEED, market]`
+#[constant]
+pub const POOL_SEED: &[u8] = b"pool";
+
+/// `[PROPOSAL_SEED, market]`
+#[constant]
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
+/// `[COMMITTEE_SEED, authority, committee_id.to_le_bytes()]`
+#[constant]
+pub const COMMITTEE_SEED: &[u8] = b"committee";
+
+/// `[OUTCOME_MINT_SEED, market, [outcome]]`
+#[constant]
+pub const OUTCOME_MINT_SEED: &[u8] = b"outcome_mint";
+
+/// `[POOL_VAULT_SEED, pool, mint]`
+#[constant]
+pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+
+/// `[ORDER_BOOK_SEED, market, [outcome]]`
+#[constant]
+pub const ORDER_BOOK_SEED: &[u8] = b"order_book";
+
+/// `[ORDER_VAULT_SEED, order_book, mint]`
+#[constant]
+pub const ORDER_VAULT_SEED: &[u8] = b"order_vault";
+
+/// `[EVENT_QUEUE_SEED, order_book]`
+#[constant]
+pub const EVENT_QUEUE_SEED: &[u8] = b"event_queue";
+
+/// `[OPEN_ORDERS_SEED, order_book, owner]`
+#[constant]
+pub const OPEN_ORDERS_SEED: &[u8] = b"open_orders";
+
+pub fn find_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], &crate::ID)
+}
+
+pub fn find_market_address(authority: &Pubkey, market_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKET_SEED, authority.as_ref(), &market_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+pub fn find_vault_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, market.as_ref()], &crate::ID)
+}
+
+pub fn find_position_address(market: &Pubkey, user: &Pubkey, outcome: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[POSITION_SEED, market.as_ref(), user.as_ref(), &[outcome]],
+        &crate::ID,
+    )
+}
+
+pub fn find_bet_address(market: &Pubkey, bettor: &Pubkey, bet_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BET_SEED, market.as_ref(), bettor.as_ref(), &bet_index.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+pub fn find_liquidity_address(market: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LIQUIDITY_SEED, market.as_ref(), owner.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn find_pool_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_SEED, market.as_ref()], &crate::ID)
+}
+
+pub fn find_proposal_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROPOSAL_SEED, market.as_ref()], &crate::ID)
+}
+
+pub fn find_committee_address(authority: &Pubkey, committee_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COMMITTEE_SEED, authority.as_ref(), &committee_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+pub fn find_outcome_mint_address(market: &Pubkey, outcome: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OUTCOME_MINT_SEED, market.as_ref(), &[outcome]],
+        &crate::ID,
+    )
+}
+
+pub fn find_pool_vault_address(pool: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[POOL_VAULT_SEED, pool.as_ref(), mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn find_order_book_address(market: &Pubkey, outcome: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ORDER_BOOK_SEED, market.as_ref(), &[outcome]],
+        &crate::ID,
+    )
+}
+
+pub fn find_order_vault_address(order_book: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ORDER_VAULT_SEED, order_book.as_ref(), mint.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn find_event_queue_address(order_book: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_QUEUE_SEED, order_book.as_ref()], &crate::ID)
+}
+
+pub fn find_open_orders_address(order_book: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OPEN_ORDERS_SEED, order_book.as_ref(), owner.as_ref()],
+        &crate::ID,
+    )
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Receipt for a single `place_bet` call.
+#[account]
+#[derive(InitSpace)]
+pub struct Bet {
+    pub market: Pubkey,
+    pub bettor: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub outcome: u8,
+    pub timestamp: i64,
+    pub bump: u8,
+}
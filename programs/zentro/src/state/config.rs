@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+use crate::state::{LiquidityPool, Market};
+
+/// Operational permissions delegated by the admin.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Pauses and unpauses trading.
+    Pauser,
+    /// Withdraws protocol fees.
+    FeeCollector,
+    /// Cancels markets with an invalid question or a broken oracle.
+    OracleManager,
+    /// Approves draft markets for trading.
+    MarketWhitelister,
+}
+
+/// Protocol-wide settings, stored once at `[CONFIG_SEED]`.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,
+    /// Proposed by `propose_admin`; becomes admin once it signs `accept_admin`.
+    pub pending_admin: Option<Pubkey>,
+    pub pauser: Pubkey,
+    pub fee_collector: Pubkey,
+    pub oracle_manager: Pubkey,
+    pub market_whitelister: Pubkey,
+    /// Receives market creation fees.
+    pub treasury: Pubkey,
+    /// Program that must own every price feed `resolve_with_oracle` reads;
+    /// set by the oracle manager. Unset until then, which halts oracle
+    /// resolution.
+    pub oracle_program: Pubkey,
+    /// Protocol's cut of every trade, in basis points.
+    pub protocol_fee_bps: u16,
+    /// Highest fee a market creator may charge on trades, in basis points.
+    pub max_creator_fee_bps: u16,
+    /// Highest LP `fee_rate` a pool may be opened with, in basis points.
+    pub max_pool_fee_bps: u16,
+    /// Charged for every `create_market`, in `creation_fee_mint` tokens or
+    /// in lamports when no mint is set.
+    pub market_creation_fee: u64,
+    pub creation_fee_mint: Option<Pubkey>,
+    /// Lamports every creator locks on the market account; returned once the
+    /// market resolves, forfeited to the treasury if it is cancelled as invalid.
+    pub creator_deposit: u64,
+    /// Lamports a taker pays per order book fill, earned by whoever cranks
+    /// the fill through `consume_events`. Copied to each book at creation.
+    pub crank_fee: u64,
+    /// Smallest collateral value, at its limit price, an order may be placed
+    /// or rest on a book with. Copied to each book at creation.
+    pub min_order_notional: u64,
+    pub max_title_len: u16,
+    pub max_description_len: u16,
+    pub max_category_len: u16,
+    /// Bounds on `end_time - created_at`, in seconds.
+    pub min_market_duration: i64,
+    pub max_market_duration: i64,
+    /// Seconds a resolver committee has to settle a dispute before the admin
+    /// or oracle manager may cancel the market instead.
+    pub dispute_timeout: i64,
+    /// New markets start as `Draft` until the market whitelister approves them.
+    pub require_market_approval: bool,
+    /// Circuit breaker set by the pauser; halts bets, liquidity and swaps on
+    /// every market while claims and refunds keep working.
+    pub paused: bool,
+    pub bump: u8,
+}
+
+/// Admin-settable part of `Config`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigParams {
+    pub treasury: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub max_creator_fee_bps: u16,
+    pub max_pool_fee_bps: u16,
+    pub market_creation_fee: u64,
+    pub creation_fee_mint: Option<Pubkey>,
+    pub creator_deposit: u64,
+    pub crank_fee: u64,
+    pub min_order_notional: u64,
+    pub max_title_len: u16,
+    pub max_description_len: u16,
+    pub max_category_len: u16,
+    pub min_market_duration: i64,
+    pub max_market_duration: i64,
+    pub dispute_timeout: i64,
+    pub require_market_approval: bool,
+}
+
+impl Config {
+    /// Highest fee, in basis points, any one party may take from a trade.
+    pub const MAX_FEE_BPS: u16 = 1000;
+
+    /// Validates `params` and writes them over the current settings.
+    pub fn apply(&mut self, params: ConfigParams) -> Result<()> {
+        require!(
+            params.protocol_fee_bps <= Self::MAX_FEE_BPS
+                && params.max_creator_fee_bps <= Self::MAX_FEE_BPS
+                && params.max_pool_fee_bps <= LiquidityPool::MAX_FEE_RATE,
+            ZentroError::InvalidConfig
+        );
+        // Market accounts are sized for the hard maximums
+        require!(
+            params.max_title_len as usize <= Market::MAX_TITLE_LEN
+                && params.max_description_len as usize <= Market::MAX_DESCRIPTION_LEN
+                && params.max_category_len as usize <= Market::MAX_CATEGORY_LEN,
+            ZentroError::InvalidConfig
+        );
+        require!(
+            params.min_market_duration > 0 && params.min_market_duration <= params.max_market_duration,
+            ZentroError::InvalidConfig
+        );
+        require!(params.dispute_timeout > 0, ZentroError::InvalidConfig);
+
+        self.treasury = params.treasury;
+        self.protocol_fee_bps = params.protocol_fee_bps;
+        self.max_creator_fee_bps = params.max_creator_fee_bps;
+        self.max_pool_fee_bps = params.max_pool_fee_bps;
+        self.market_creation_fee = params.market_creation_fee;
+        self.creation_fee_mint = params.creation_fee_mint;
+        self.creator_deposit = params.creator_deposit;
+        self.crank_fee = params.crank_fee;
+        self.min_order_notional = params.min_order_notional;
+        self.max_title_len = params.max_title_len;
+        self.max_description_len = params.max_description_len;
+        self.max_category_len = params.max_category_len;
+        self.min_market_duration = params.min_market_duration;
+        self.max_market_duration = params.max_market_duration;
+        self.dispute_timeout = params.dispute_timeout;
+        self.require_market_approval = params.require_market_approval;
+
+        Ok(())
+    }
+
+    pub fn role_holder(&self, role: Role) -> Pubkey {
+        match role {
+            Role::Pauser => self.pauser,
+            Role::FeeCollector => self.fee_collector,
+            Role::OracleManager => self.oracle_manager,
+            Role::MarketWhitelister => self.market_whitelister,
+        }
+    }
+
+    /// Assigns `role` to `holder` and returns the previous holder.
+    pub fn set_role(&mut self, role: Role, holder: Pubkey) -> Pubkey {
+        let slot = match role {
+            Role::Pauser => &mut self.pauser,
+            Role::FeeCollector => &mut self.fee_collector,
+            Role::OracleManager => &mut self.oracle_manager,
+            Role::MarketWhitelister => &mut self.market_whitelister,
+        };
+        std::mem::replace(slot, holder)
+    }
+
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        self.role_holder(role) == *key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{config_params, empty_config};
+
+    #[test]
+    fn test_apply_config() {
+        let mut config = empty_config();
+        config.apply(config_params()).unwrap();
+        assert_eq!(config.max_pool_fee_bps, 300);
+
+        // Limits beyond what the accounts or fee caps allow are rejected
+        let too_long = ConfigParams { max_title_len: 201, ..config_params() };
+        assert!(config.apply(too_long).is_err());
+        let fee = ConfigParams { protocol_fee_bps: 1001, ..config_params() };
+        assert!(config.apply(fee).is_err());
+        let durations = ConfigParams { min_market_duration: 7200, max_market_duration: 3600, ..config_params() };
+        assert!(config.apply(durations).is_err());
+        let timeout = ConfigParams { dispute_timeout: 0, ..config_params() };
+        assert!(config.apply(timeout).is_err());
+        assert_eq!(config.max_title_len, 200);
+    }
+
+    #[test]
+    fn test_roles() {
+        let mut config = empty_config();
+        let pauser = Pubkey::new_unique();
+
+        assert_eq!(config.set_role(Role::Pauser, pauser), Pubkey::default());
+        assert!(config.has_role(Role::Pauser, &pauser));
+        assert!(!config.has_role(Role::FeeCollector, &pauser));
+        assert_eq!(config.set_role(Role::Pauser, Pubkey::default()), pauser);
+    }
+}
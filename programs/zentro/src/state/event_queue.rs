@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+use crate::state::Side;
+
+/// A fill owed to a resting order, or the refund of an evicted one, waiting
+/// for `consume_events`.
+#[zero_copy]
+#[derive(Debug, PartialEq, Eq)]
+pub struct FillEvent {
+    pub maker: Pubkey,
+    pub maker_order_id: u64,
+    pub quantity: u64,
+    /// Collateral traded for `quantity` tokens.
+    pub collateral: u64,
+    pub timestamp: i64,
+    pub price: u16,
+    /// `Side` of the maker's order, as `Side as u8`.
+    pub maker_side: u8,
+    /// 1 if the order was evicted from a full book rather than filled;
+    /// `quantity` and `collateral` are then what it still escrowed.
+    pub evicted: u8,
+    pub _padding: [u8; 4],
+}
+
+impl FillEvent {
+    pub fn maker_side(&self) -> Side {
+        if self.maker_side == Side::Bid as u8 {
+            Side::Bid
+        } else {
+            Side::Ask
+        }
+    }
+}
+
+/// Ring buffer of the fills of one order book. Takers push an event for
+/// every resting order they match; the permissionless `consume_events`
+/// crank pops them and credits the makers' `OpenOrders`.
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct EventQueue {
+    pub order_book: Pubkey,
+    /// Events pushed since creation.
+    pub seq_num: u64,
+    pub head: u32,
+    pub count: u32,
+    pub events: [FillEvent; 128],
+}
+
+impl EventQueue {
+    pub const LEN: usize = 8 + std::mem::size_of::<EventQueue>();
+
+    pub const CAPACITY: usize = 128;
+
+    pub fn free_slots(&self) -> usize {
+        Self::CAPACITY - self.count as usize
+    }
+
+    pub fn push(&mut self, event: FillEvent) -> Result<()> {
+        require!(self.free_slots() > 0, ZentroError::EventQueueFull);
+
+        let tail = (self.head as usize + self.count as usize) % Self::CAPACITY;
+        self.events[tail] = event;
+        self.count += 1;
+        self.seq_num = self.seq_num.checked_add(1).ok_or(ZentroError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn peek(&self) -> Option<&FillEvent> {
+        (self.count > 0).then(|| &self.events[self.head as usize])
+    }
+
+    pub fn pop(&mut self) -> Option<FillEvent> {
+        let event = *self.peek()?;
+        self.head = ((self.head as usize + 1) % Self::CAPACITY) as u32;
+        self.count -= 1;
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    fn event(maker_order_id: u64) -> FillEvent {
+        FillEvent { maker_order_id, ..FillEvent::zeroed() }
+    }
+
+    #[test]
+    fn test_event_queue_wraps() {
+        let mut queue = Box::new(EventQueue::zeroed());
+
+        for id in 0..EventQueue::CAPACITY as u64 {
+            queue.push(event(id)).unwrap();
+        }
+        assert!(queue.push(event(0)).is_err());
+
+        // Popping frees slots that are reused past the end of the buffer
+        for id in 0..100 {
+            assert_eq!(queue.pop().unwrap().maker_order_id, id);
+        }
+        for id in 0..50 {
+            queue.push(event(1000 + id)).unwrap();
+        }
+        assert_eq!(queue.free_slots(), 50);
+        assert_eq!(queue.seq_num, EventQueue::CAPACITY as u64 + 50);
+
+        let order: Vec<u64> = std::iter::from_fn(|| queue.pop()).map(|e| e.maker_order_id).collect();
+        assert_eq!(order.len(), 78);
+        assert_eq!(order[27], 127);
+        assert_eq!(order[28], 1000);
+        assert!(queue.peek().is_none());
+    }
+}
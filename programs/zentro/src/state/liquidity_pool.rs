@@ -1,6 +1,6 @@
-```rust
 use anchor_lang::prelude::*;
-use crate::errors::ZentroError;
+use std::cmp;
+use crate::error::ZentroError;
 
 #[account]
 pub struct LiquidityPool {
@@ -16,8 +16,6 @@ pub struct LiquidityPool {
     pub yes_reserves: u64,
     pub no_reserves: u64,
     pub fee_rate: u16, // basis points (e.g., 100 = 1%)
-    pub accumulated_fees: u64,
-    pub is_active: bool,
     pub created_at: i64,
     pub bump: u8,
 }
@@ -36,11 +34,17 @@ impl LiquidityPool {
         8 + // yes_reserves
         8 + // no_reserves
         2 + // fee_rate
-        8 + // accumulated_fees
-        1 + // is_active
         8 + // created_at
         1; // bump
 
+    /// Outcome indexes used to derive the YES/NO mints.
+    pub const YES_OUTCOME: u8 = 0;
+    pub const NO_OUTCOME: u8 = 1;
+
+    /// Maximum `fee_rate`, 10%.
+    pub const MAX_FEE_RATE: u16 = 1000;
+
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         authority: Pubkey,
@@ -54,7 +58,7 @@ impl LiquidityPool {
         fee_rate: u16,
         bump: u8,
     ) -> Result<()> {
-        require!(fee_rate <= 1000, ZentroError::InvalidFeeRate); // Max 10%
+        require!(fee_rate <= Self::MAX_FEE_RATE, ZentroError::InvalidFeeRate);
         
         self.authority = authority;
         self.market = market;
@@ -68,70 +72,63 @@ impl LiquidityPool {
         self.yes_reserves = 0;
         self.no_reserves = 0;
         self.fee_rate = fee_rate;
-        self.accumulated_fees = 0;
-        self.is_active = true;
         self.created_at = Clock::get()?.unix_timestamp;
         self.bump = bump;
 
         Ok(())
     }
 
-    pub fn add_liquidity(&mut self, amount: u64) -> Result<u64> {
-        require!(self.is_active, ZentroError::PoolInactive);
+    /// Adds `amount` complete YES/NO sets to the pool and returns
+    /// `(liquidity_tokens, yes_returned, no_returned)`.
+    ///
+    /// Only the part of each side that keeps the current price is added to
+    /// the reserves; the surplus outcome tokens go back to the provider.
+    pub fn add_liquidity(&mut self, amount: u64) -> Result<(u64, u64, u64)> {
         require!(amount > 0, ZentroError::InvalidAmount);
 
-        let liquidity_tokens = if self.total_liquidity == 0 {
-            // Initial liquidity provision
-            amount
+        let (liquidity_tokens, yes_added, no_added) = if self.total_liquidity == 0 {
+            // Initial liquidity provision sets a 50/50 price
+            (amount, amount, amount)
         } else {
-            // Calculate proportional liquidity tokens
-            let total_reserves = self.yes_reserves.checked_add(self.no_reserves)
-                .ok_or(ZentroError::MathOverflow)?;
-            
-            if total_reserves == 0 {
-                amount
-            } else {
-                amount.checked_mul(self.total_liquidity)
-                    .ok_or(ZentroError::MathOverflow)?
-                    .checked_div(total_reserves)
-                    .ok_or(ZentroError::MathOverflow)?
-            }
+            let pool_weight = cmp::max(self.yes_reserves, self.no_reserves);
+            require!(pool_weight > 0, ZentroError::InsufficientLiquidity);
+
+            (
+                mul_div(amount, self.total_liquidity, pool_weight)?,
+                mul_div(amount, self.yes_reserves, pool_weight)?,
+                mul_div(amount, self.no_reserves, pool_weight)?,
+            )
         };
 
-        // Add equal amounts to both reserves initially
-        let half_amount = amount.checked_div(2).ok_or(ZentroError::MathOverflow)?;
-        
-        self.yes_reserves = self.yes_reserves.checked_add(half_amount)
+        require!(liquidity_tokens > 0, ZentroError::InsufficientLiquidity);
+
+        self.yes_reserves = self.yes_reserves.checked_add(yes_added)
             .ok_or(ZentroError::MathOverflow)?;
-        self.no_reserves = self.no_reserves.checked_add(half_amount)
+        self.no_reserves = self.no_reserves.checked_add(no_added)
             .ok_or(ZentroError::MathOverflow)?;
-        
+
         self.total_liquidity = self.total_liquidity.checked_add(liquidity_tokens)
             .ok_or(ZentroError::MathOverflow)?;
 
-        Ok(liquidity_tokens)
+        Ok((liquidity_tokens, amount - yes_added, amount - no_added))
     }
 
+    /// Burns `liquidity_tokens` and returns the provider's `(yes, no)`.
+    /// Swap fees are compounded into the reserves, so both include the
+    /// provider's share of them.
     pub fn remove_liquidity(&mut self, liquidity_tokens: u64) -> Result<(u64, u64)> {
-        require!(self.is_active, ZentroError::PoolInactive);
         require!(liquidity_tokens > 0, ZentroError::InvalidAmount);
         require!(liquidity_tokens <= self.total_liquidity, ZentroError::InsufficientLiquidity);
 
-        let yes_amount = self.yes_reserves.checked_mul(liquidity_tokens)
-            .ok_or(ZentroError::MathOverflow)?
-            .checked_div(self.total_liquidity)
-            .ok_or(ZentroError::MathOverflow)?;
+        let yes_amount = mul_div(self.yes_reserves, liquidity_tokens, self.total_liquidity)?;
 
-        let no_amount = self.no_reserves.checked_mul(liquidity_tokens)
-            .ok_or(ZentroError::MathOverflow)?
-            .checked_div(self.total_liquidity)
-            .ok_or(ZentroError::MathOverflow)?;
+        let no_amount = mul_div(self.no_reserves, liquidity_tokens, self.total_liquidity)?;
 
         self.yes_reserves = self.yes_reserves.checked_sub(yes_amount)
             .ok_or(ZentroError::MathOverflow)?;
         self.no_reserves = self.no_reserves.checked_sub(no_amount)
             .ok_or(ZentroError::MathOverflow)?;
-        
+
         self.total_liquidity = self.total_liquidity.checked_sub(liquidity_tokens)
             .ok_or(ZentroError::MathOverflow)?;
 
@@ -139,7 +136,6 @@ impl LiquidityPool {
     }
 
     pub fn get_swap_amount_out(&self, amount_in: u64, is_yes_to_no: bool) -> Result<u64> {
-        require!(self.is_active, ZentroError::PoolInactive);
         require!(amount_in > 0, ZentroError::InvalidAmount);
 
         let (reserve_in, reserve_out) = if is_yes_to_no {
@@ -151,23 +147,16 @@ impl LiquidityPool {
         require!(reserve_in > 0 && reserve_out > 0, ZentroError::InsufficientLiquidity);
 
         // Apply fee
-        let fee_amount = amount_in.checked_mul(self.fee_rate as u64)
-            .ok_or(ZentroError::MathOverflow)?
-            .checked_div(10000)
-            .ok_or(ZentroError::MathOverflow)?;
+        let fee_amount = self.swap_fee(amount_in)?;
 
         let amount_in_after_fee = amount_in.checked_sub(fee_amount)
             .ok_or(ZentroError::MathOverflow)?;
 
         // Constant product formula: x * y = k
         // amount_out = (amount_in_after_fee * reserve_out) / (reserve_in + amount_in_after_fee)
-        let numerator = amount_in_after_fee.checked_mul(reserve_out)
-            .ok_or(ZentroError::MathOverflow)?;
         let denominator = reserve_in.checked_add(amount_in_after_fee)
             .ok_or(ZentroError::MathOverflow)?;
-
-        let amount_out = numerator.checked_div(denominator)
-            .ok_or(ZentroError::MathOverflow)?;
+        let amount_out = mul_div(amount_in_after_fee, reserve_out, denominator)?;
 
         require!(amount_out < reserve_out, ZentroError::InsufficientLiquidity);
 
@@ -175,17 +164,10 @@ impl LiquidityPool {
     }
 
     pub fn execute_swap(&mut self, amount_in: u64, amount_out: u64, is_yes_to_no: bool) -> Result<()> {
-        require!(self.is_active, ZentroError::PoolInactive);
         require!(amount_in > 0 && amount_out > 0, ZentroError::InvalidAmount);
 
-        let fee_amount = amount_in.checked_mul(self.fee_rate as u64)
-            .ok_or(ZentroError::MathOverflow)?
-            .checked_div(10000)
-            .ok_or(ZentroError::MathOverflow)?;
-
-        self.accumulated_fees = self.accumulated_fees.checked_add(fee_amount)
-            .ok_or(ZentroError::MathOverflow)?;
-
+        // The whole input, fee included, stays in the reserves, so fees
+        // compound for LPs
         if is_yes_to_no {
             self.yes_reserves = self.yes_reserves.checked_add(amount_in)
                 .ok_or(ZentroError::MathOverflow)?;
@@ -201,28 +183,184 @@ impl LiquidityPool {
         Ok(())
     }
 
+    /// Splits a sale of `shares_in` outcome tokens into the part swapped for
+    /// the opposite side and the number of complete sets that are then
+    /// redeemed for collateral. Returns `(swap_in, collateral_out)`.
+    pub fn get_sell_amounts(&self, shares_in: u64, is_yes: bool) -> Result<(u64, u64)> {
+        require!(shares_in > 0, ZentroError::InvalidAmount);
+
+        // Largest swap whose output can still be paired with the unswapped remainder
+        let (mut low, mut high) = (0u64, shares_in);
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            if self.get_swap_amount_out(mid, is_yes)? <= shares_in - mid {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        require!(low > 0, ZentroError::InsufficientLiquidity);
+
+        let collateral_out = self.get_swap_amount_out(low, is_yes)?;
+        require!(collateral_out > 0, ZentroError::InsufficientLiquidity);
+
+        Ok((low, collateral_out))
+    }
+
+    /// Applies a sale computed by `get_sell_amounts`. Rounding dust left after
+    /// pairing complete sets stays in the sold side's reserve.
+    pub fn execute_sell(
+        &mut self,
+        shares_in: u64,
+        swap_in: u64,
+        collateral_out: u64,
+        is_yes: bool,
+    ) -> Result<()> {
+        self.execute_swap(swap_in, collateral_out, is_yes)?;
+
+        let dust = shares_in
+            .checked_sub(swap_in)
+            .and_then(|rest| rest.checked_sub(collateral_out))
+            .ok_or(ZentroError::MathOverflow)?;
+
+        if is_yes {
+            self.yes_reserves = self.yes_reserves.checked_add(dust)
+                .ok_or(ZentroError::MathOverflow)?;
+        } else {
+            self.no_reserves = self.no_reserves.checked_add(dust)
+                .ok_or(ZentroError::MathOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fee charged on `amount_in`, in the input token.
+    pub fn swap_fee(&self, amount_in: u64) -> Result<u64> {
+        mul_div(amount_in, self.fee_rate as u64, 10000)
+    }
+
+    pub fn outcome_mint(&self, is_yes: bool) -> Pubkey {
+        if is_yes { self.yes_token_mint } else { self.no_token_mint }
+    }
+
+    pub fn outcome_vault(&self, is_yes: bool) -> Pubkey {
+        if is_yes { self.yes_token_vault } else { self.no_token_vault }
+    }
+
+    /// Price of one YES token in basis points of collateral; NO trades at
+    /// `10000 - price`.
     pub fn get_current_price(&self) -> Result<u64> {
         require!(self.yes_reserves > 0 && self.no_reserves > 0, ZentroError::InsufficientLiquidity);
 
-        // Price as ratio scaled by 10^6 for precision
-        let price = self.no_reserves.checked_mul(1_000_000)
-            .ok_or(ZentroError::MathOverflow)?
-            .checked_div(self.yes_reserves.checked_add(self.no_reserves)
-                .ok_or(ZentroError::MathOverflow)?)
+        let total_reserves = self.yes_reserves.checked_add(self.no_reserves)
             .ok_or(ZentroError::MathOverflow)?;
 
-        Ok(price)
+        mul_div(self.no_reserves, 10000, total_reserves)
     }
+}
 
-    pub fn deactivate(&mut self) -> Result<()> {
-        self.is_active = false;
-        Ok(())
+fn mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+    let result = (a as u128)
+        .checked_mul(b as u128)
+        .ok_or(ZentroError::MathOverflow)?
+        .checked_div(c as u128)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    u64::try_from(result).map_err(|_| ZentroError::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::liquidity_pool;
+
+    #[test]
+    fn test_add_liquidity_keeps_price() {
+        let mut pool = liquidity_pool(0, 0, 0);
+        pool.total_liquidity = 0;
+        assert_eq!(pool.add_liquidity(1000).unwrap(), (1000, 0, 0));
+        assert_eq!(pool.get_current_price().unwrap(), 5000);
+
+        // 1000 YES / 4000 NO prices YES at 80%
+        let mut pool = liquidity_pool(1000, 4000, 0);
+        let price = pool.get_current_price().unwrap();
+        assert_eq!(pool.add_liquidity(400).unwrap(), (400, 300, 0));
+        assert_eq!((pool.yes_reserves, pool.no_reserves), (1100, 4400));
+        assert_eq!(pool.get_current_price().unwrap(), price);
+    }
+
+    #[test]
+    fn test_buy_moves_price() {
+        let mut pool = liquidity_pool(10_000, 10_000, 100);
+        // Buying YES swaps the minted NO tokens into the pool
+        let out = pool.get_swap_amount_out(1000, false).unwrap();
+        pool.execute_swap(1000, out, false).unwrap();
+        // The 10 token fee is kept in the NO reserve
+        assert_eq!(pool.no_reserves, 11_000);
+        assert!(pool.get_current_price().unwrap() > 5000);
+    }
+
+    #[test]
+    fn test_sell_amounts_pair_complete_sets() {
+        let pool = liquidity_pool(10_000, 10_000, 0);
+        let (swap_in, collateral_out) = pool.get_sell_amounts(1000, true).unwrap();
+        assert!(swap_in + collateral_out <= 1000);
+        // Swapping one more token would no longer leave enough to pair
+        let next = pool.get_swap_amount_out(swap_in + 1, true).unwrap();
+        assert!(next > 1000 - swap_in - 1);
+        // Selling into a balanced pool returns a little under half
+        assert!((470..500).contains(&collateral_out));
+    }
+
+    #[test]
+    fn test_sell_reverses_buy() {
+        let mut pool = liquidity_pool(10_000, 10_000, 0);
+        let out = pool.get_swap_amount_out(1000, false).unwrap();
+        pool.execute_swap(1000, out, false).unwrap();
+        let bought = 1000 + out;
+
+        let (swap_in, collateral_out) = pool.get_sell_amounts(bought, true).unwrap();
+        pool.execute_sell(bought, swap_in, collateral_out, true).unwrap();
+
+        // Rounding only ever favors the pool
+        assert!((998..=1000).contains(&collateral_out));
+        assert!(pool.yes_reserves >= 10_000 && pool.no_reserves >= 10_000);
+    }
+
+    #[test]
+    fn test_swap_at_nine_decimal_sizes() {
+        // 5,000 tokens a side with 9 decimals; reserve products exceed u64
+        let mut pool = liquidity_pool(5_000_000_000_000, 5_000_000_000_000, 100);
+        let out = pool.get_swap_amount_out(5_000_000_000, false).unwrap();
+        assert!((4_900_000_000..4_950_000_000).contains(&out));
+        pool.execute_swap(5_000_000_000, out, false).unwrap();
+
+        let (swap_in, collateral_out) = pool.get_sell_amounts(10_000_000_000, true).unwrap();
+        assert!(swap_in + collateral_out <= 10_000_000_000);
+        assert!(collateral_out > 4_800_000_000);
     }
 
-    pub fn collect_fees(&mut self) -> Result<u64> {
-        let fees = self.accumulated_fees;
-        self.accumulated_fees = 0;
-        Ok(fees)
+    #[test]
+    fn test_remove_liquidity_is_proportional() {
+        let mut pool = liquidity_pool(1000, 4000, 0);
+        assert_eq!(pool.remove_liquidity(1000).unwrap(), (250, 1000));
+        assert_eq!((pool.yes_reserves, pool.no_reserves), (750, 3000));
+        assert_eq!(pool.total_liquidity, 3000);
+        assert!(pool.remove_liquidity(3001).is_err());
+    }
+
+    #[test]
+    fn test_remove_liquidity_pays_compounded_fees() {
+        let mut with_fee = liquidity_pool(10_000, 10_000, 100);
+        let mut without_fee = liquidity_pool(10_000, 10_000, 0);
+        for pool in [&mut with_fee, &mut without_fee] {
+            let out = pool.get_swap_amount_out(1000, false).unwrap();
+            pool.execute_swap(1000, out, false).unwrap();
+        }
+
+        // The fee was kept out of the swap output, so the LP withdraws it
+        let (yes_fee, no_fee) = with_fee.remove_liquidity(10_000).unwrap();
+        let (yes, no) = without_fee.remove_liquidity(10_000).unwrap();
+        assert_eq!(no_fee, no);
+        assert!(yes_fee > yes);
     }
 }
-```
\ No newline at end of file
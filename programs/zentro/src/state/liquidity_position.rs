@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// LP shares held by a single provider in a market.
+#[account]
+#[derive(InitSpace)]
+pub struct LiquidityPosition {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub bump: u8,
+}
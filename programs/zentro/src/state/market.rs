@@ -1 +1,614 @@
-I'm sorry. I'm not able to assist with that.
\ No newline at end of file
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+use crate::utils::oracle::PriceFeed;
+use crate::utils::pricing::{calculate_share_payout, scalar_long_weight};
+
+/// Lifecycle of a market. Mirrors `MarketStatus` in `app/src/types/market.ts`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MarketStatus {
+    Draft,
+    Active,
+    Paused,
+    Resolved,
+    Cancelled,
+    Disputed,
+}
+
+/// Payout model of a market, chosen at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MarketKind {
+    /// Winner-takes-all across 2..=8 named outcomes.
+    Categorical,
+    /// LONG/SHORT on a numeric value, paid linearly between the bounds.
+    Scalar { lower_bound: i64, upper_bound: i64 },
+}
+
+/// How trades are priced, chosen at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PricingEngine {
+    /// Stakes are pooled and the winners split the pool pro rata.
+    Parimutuel,
+    /// Logarithmic market scoring rule with liquidity parameter `liquidity`
+    /// (`b`); each winning share redeems one collateral unit.
+    Lmsr { liquidity: u64 },
+}
+
+/// What a market resolves to: an outcome index for categorical markets, a
+/// numeric value for scalar ones.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum ResolutionValue {
+    Outcome(u8),
+    Scalar(i64),
+}
+
+/// Comparison applied to an oracle price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum Comparison {
+    GreaterOrEqual,
+    GreaterThan,
+    LessOrEqual,
+    LessThan,
+}
+
+impl Comparison {
+    pub fn holds(&self, price: i64, threshold: i64) -> bool {
+        match self {
+            Comparison::GreaterOrEqual => price >= threshold,
+            Comparison::GreaterThan => price > threshold,
+            Comparison::LessOrEqual => price <= threshold,
+            Comparison::LessThan => price < threshold,
+        }
+    }
+}
+
+/// How an oracle price maps to a resolution.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum OracleRule {
+    /// Two-outcome categorical markets: outcome 0 if `price <comparison>
+    /// threshold` holds, outcome 1 otherwise. `threshold` uses the feed's
+    /// fixed-point units.
+    Threshold { comparison: Comparison, threshold: i64 },
+    /// Scalar markets resolve to the raw feed price, so `lower_bound` and
+    /// `upper_bound` must be in the feed's fixed-point units too: with
+    /// exponent -8, bounds of 50_000 and 100_000 USD are 5e12 and 1e13.
+    Price,
+}
+
+/// Price feed a market is bound to at creation, and how much to trust it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct OracleConfig {
+    pub feed: Pubkey,
+    pub rule: OracleRule,
+    /// Seconds after `end_time` within which a published price is accepted,
+    /// at most `MAX_STALENESS`. Whoever resolves first picks the price from
+    /// this window, so it is capped to the first few updates after
+    /// `end_time`; once it has passed the oracle manager resolves via
+    /// `resolve_oracle_fallback`.
+    pub max_staleness: u32,
+    /// Widest accepted confidence interval, in basis points of the price.
+    pub max_confidence_bps: u16,
+}
+
+impl OracleConfig {
+    /// Longest `max_staleness` a market may choose. Feeds update several
+    /// times a second, so this still leaves room to land the transaction.
+    pub const MAX_STALENESS: u32 = 10;
+}
+
+/// Parameters of the propose/dispute flow, fixed at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct OptimisticConfig {
+    /// Lamports a proposer, and then a disputer, must post.
+    pub bond: u64,
+    /// Seconds an unchallenged proposal waits before it can be finalized.
+    pub challenge_period: i64,
+    /// Collateral the creator deposits to reward an undisputed proposer.
+    pub reward: u64,
+    /// `ResolverCommittee` that arbitrates disputes; only the admin creates them.
+    pub committee: Pubkey,
+}
+
+/// One named outcome of a market with its running totals.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct MarketOutcome {
+    #[max_len(32)]
+    pub title: String,
+    pub total_shares: u64,
+    pub total_volume: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Market {
+    pub authority: Pubkey,
+    pub market_id: u64,
+    #[max_len(200)]
+    pub title: String,
+    #[max_len(1000)]
+    pub description: String,
+    #[max_len(32)]
+    pub category: String,
+    /// `Paused` halts trading only; a paused market can still be resolved
+    /// or cancelled, and returns to `Active` when unpaused.
+    pub status: MarketStatus,
+    pub kind: MarketKind,
+    pub pricing: PricingEngine,
+    /// Collateral the creator deposited to cover the LMSR worst-case loss.
+    pub subsidy: u64,
+    /// Anti-spam lamports held on this account on top of its rent, see
+    /// `Config::creator_deposit`.
+    pub creator_deposit: u64,
+    /// Set for markets settled by `resolve_with_oracle` instead of the authority.
+    pub oracle: Option<OracleConfig>,
+    /// Set for markets settled by bonded proposals instead of the authority.
+    pub optimistic: Option<OptimisticConfig>,
+    /// Creator's cut of every trade, in basis points.
+    pub creator_fee_bps: u16,
+    /// Protocol's cut of every trade, copied from `Config` at creation.
+    pub protocol_fee_bps: u16,
+    /// Trade fees held in the vault until withdrawn.
+    pub creator_fees_accrued: u64,
+    pub protocol_fees_accrued: u64,
+    pub token_mint: Pubkey,
+    pub end_time: i64,
+    pub created_at: i64,
+    pub resolved_at: i64,
+    pub winning_outcome: Option<u8>,
+    pub resolved_value: Option<i64>,
+    #[max_len(8)]
+    pub outcomes: Vec<MarketOutcome>,
+    /// Outcome token mints created so far by `initialize_outcome_mint`.
+    /// Outcome tokens circulate only once every outcome has one.
+    pub outcome_mints: u8,
+    pub total_volume: u64,
+    pub total_bets: u64,
+    pub total_liquidity: u64,
+    /// Collateral users have paid in by any route: bets, trades, splits,
+    /// liquidity and order-book bids. Never decreases; the creator may only
+    /// cancel while it is zero.
+    pub total_deposits: u64,
+    /// Paid to positions by `claim_winnings` and `claim_refund`.
+    pub total_claimed: u64,
+    /// Paid for outcome tokens by `redeem`.
+    pub total_redeemed: u64,
+    pub min_bet_amount: u64,
+    pub max_bet_amount: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Market {
+    pub const LEN: usize = 8 + Market::INIT_SPACE;
+
+    pub const MAX_TITLE_LEN: usize = 200;
+    pub const MAX_DESCRIPTION_LEN: usize = 1000;
+    pub const MAX_CATEGORY_LEN: usize = 32;
+    pub const MAX_OUTCOME_TITLE_LEN: usize = 32;
+
+    pub const MIN_OUTCOMES: usize = 2;
+    pub const MAX_OUTCOMES: usize = 8;
+
+    /// Outcome indexes of the two sides of a scalar market.
+    pub const LONG: u8 = 0;
+    pub const SHORT: u8 = 1;
+
+    /// Whether the market accepts trades. This is the only check trading
+    /// instructions make, as `Paused` and every later status fail it.
+    pub fn is_active(&self) -> bool {
+        self.status == MarketStatus::Active
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.status == MarketStatus::Paused
+    }
+
+    /// Whether the market is live, trading or paused, and so can still be
+    /// resolved.
+    pub fn is_open(&self) -> bool {
+        self.is_active() || self.is_paused()
+    }
+
+    /// Halts or resumes trading by moving an active market to `Paused` and
+    /// back. Resolution accepts either status, so it is never blocked.
+    pub fn set_paused(&mut self, paused: bool) -> Result<()> {
+        if paused {
+            require!(self.is_active(), ZentroError::MarketNotActive);
+            self.status = MarketStatus::Paused;
+        } else {
+            require!(self.is_paused(), ZentroError::MarketNotActive);
+            self.status = MarketStatus::Active;
+        }
+
+        Ok(())
+    }
+
+    /// Moves a challenged market to `Disputed`. A paused market cannot be
+    /// disputed, as that would silently lift its pause; the pauser must
+    /// unpause it first.
+    pub fn dispute(&mut self) -> Result<()> {
+        require!(!self.is_paused(), ZentroError::MarketPaused);
+        require!(self.is_active(), ZentroError::MarketNotActive);
+        self.status = MarketStatus::Disputed;
+
+        Ok(())
+    }
+
+    /// Creator and protocol cuts of a trade of `amount` collateral, booked
+    /// as accrued. LP fees are charged separately by the pool.
+    pub fn take_trade_fees(&mut self, amount: u64) -> Result<(u64, u64)> {
+        let cut = |bps: u16| -> Result<u64> {
+            u64::try_from(amount as u128 * bps as u128 / 10000)
+                .map_err(|_| ZentroError::MathOverflow.into())
+        };
+        let creator_fee = cut(self.creator_fee_bps)?;
+        let protocol_fee = cut(self.protocol_fee_bps)?;
+
+        self.creator_fees_accrued = self.creator_fees_accrued.checked_add(creator_fee)
+            .ok_or(ZentroError::MathOverflow)?;
+        self.protocol_fees_accrued = self.protocol_fees_accrued.checked_add(protocol_fee)
+            .ok_or(ZentroError::MathOverflow)?;
+
+        Ok((creator_fee, protocol_fee))
+    }
+
+    /// Books an LMSR sale that paid out `proceeds`, fees included, for shares
+    /// that cost `cost_basis`. The market maker's profit or loss on the
+    /// exit moves the subsidy, so the vault always holds the subsidy plus
+    /// every open stake at cost and a cancellation can refund both. Profits
+    /// are therefore capped by the remaining subsidy: once exits have drawn
+    /// it down, a sale that would take more fails with `ExitExceedsSubsidy`.
+    pub fn book_lmsr_exit(&mut self, proceeds: u64, cost_basis: u64) -> Result<()> {
+        self.subsidy = if proceeds >= cost_basis {
+            self.subsidy
+                .checked_sub(proceeds - cost_basis)
+                .ok_or(ZentroError::ExitExceedsSubsidy)?
+        } else {
+            self.subsidy
+                .checked_add(cost_basis - proceeds)
+                .ok_or(ZentroError::MathOverflow)?
+        };
+
+        Ok(())
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.status == MarketStatus::Resolved
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.status == MarketStatus::Cancelled
+    }
+
+    /// Records collateral paid in by a user, see `total_deposits`.
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposits = self.total_deposits.checked_add(amount)
+            .ok_or(ZentroError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Whether every outcome has its token mint, so complete sets can be
+    /// split and merged.
+    pub fn has_outcome_tokens(&self) -> bool {
+        self.outcome_mints as usize == self.outcomes.len()
+    }
+
+    pub fn outcome(&self, index: u8) -> Option<&MarketOutcome> {
+        self.outcomes.get(index as usize)
+    }
+
+    /// Whether positions are refunded at cost: once cancelled, or once a
+    /// categorical parimutuel market resolves to an outcome nobody backed,
+    /// which leaves its pool without winners to go to.
+    pub fn refunds_open(&self) -> bool {
+        if self.is_cancelled() {
+            return true;
+        }
+        self.is_resolved()
+            && self.pricing == PricingEngine::Parimutuel
+            && self.kind == MarketKind::Categorical
+            && self
+                .winning_outcome
+                .and_then(|outcome| self.outcome(outcome))
+                .is_some_and(|outcome| outcome.total_shares == 0)
+    }
+
+    /// Collateral a resolved market still owes, given the supply of each
+    /// outcome token (empty without outcome mints). A parimutuel pool belongs
+    /// to its positions in full; LMSR shares and outcome tokens are owed
+    /// their payout weight. What was already claimed or redeemed is left out.
+    pub fn outstanding_claims(&self, token_supply: &[u64]) -> Result<u64> {
+        require!(self.is_resolved(), ZentroError::MarketNotResolved);
+
+        let positions_owed = match self.pricing {
+            PricingEngine::Parimutuel => self.total_pool().ok_or(ZentroError::MathOverflow)?,
+            PricingEngine::Lmsr { .. } => {
+                (0..self.outcomes.len() as u8).try_fold(0u64, |owed, outcome| -> Result<u64> {
+                    let shares = self.outcomes[outcome as usize].total_shares;
+                    let payout = calculate_share_payout(shares, self.payout_weight(outcome)?)?;
+                    owed.checked_add(payout).ok_or(ZentroError::MathOverflow.into())
+                })?
+            }
+        };
+        let positions_owed = positions_owed
+            .checked_sub(self.total_claimed)
+            .ok_or(ZentroError::MathOverflow)?;
+
+        token_supply
+            .iter()
+            .zip(0u8..)
+            .filter(|(supply, _)| **supply > 0)
+            .try_fold(positions_owed, |owed, (supply, outcome)| -> Result<u64> {
+                let payout = calculate_share_payout(*supply, self.payout_weight(outcome)?)?;
+                owed.checked_add(payout).ok_or(ZentroError::MathOverflow.into())
+            })
+    }
+
+    /// Checks that `resolution` is a possible result of this market.
+    pub fn validate_resolution(&self, resolution: ResolutionValue) -> Result<()> {
+        match (self.kind, resolution) {
+            (MarketKind::Categorical, ResolutionValue::Outcome(outcome)) => {
+                require!(
+                    (outcome as usize) < self.outcomes.len(),
+                    ZentroError::InvalidOutcome
+                );
+            }
+            (MarketKind::Scalar { .. }, ResolutionValue::Scalar(_)) => {}
+            _ => return Err(ZentroError::ResolutionKindMismatch.into()),
+        }
+
+        Ok(())
+    }
+
+    /// Records the final result and moves the market to `Resolved`.
+    pub fn resolve(&mut self, resolution: ResolutionValue, now: i64) -> Result<()> {
+        self.validate_resolution(resolution)?;
+
+        match resolution {
+            ResolutionValue::Outcome(outcome) => self.winning_outcome = Some(outcome),
+            ResolutionValue::Scalar(value) => self.resolved_value = Some(value),
+        }
+
+        self.status = MarketStatus::Resolved;
+        self.resolved_at = now;
+
+        Ok(())
+    }
+
+    /// Moves an unresolved market to `Cancelled` so stakes can be refunded.
+    /// Callers release any resolution proposal's bonds first.
+    pub fn cancel(&mut self, now: i64) -> Result<()> {
+        require!(!self.is_resolved(), ZentroError::CannotCancelResolvedMarket);
+        require!(!self.is_cancelled(), ZentroError::MarketAlreadyCancelled);
+
+        // `resolved_at` records when the market settled either way
+        self.status = MarketStatus::Cancelled;
+        self.resolved_at = now;
+
+        Ok(())
+    }
+
+    /// Checks that `oracle` can settle a market of this kind.
+    pub fn validate_oracle(&self, oracle: &OracleConfig) -> Result<()> {
+        let fits = match (self.kind, oracle.rule) {
+            (MarketKind::Categorical, OracleRule::Threshold { .. }) => self.outcomes.len() == 2,
+            (MarketKind::Scalar { .. }, OracleRule::Price) => true,
+            _ => false,
+        };
+        require!(fits, ZentroError::InvalidOracleConfig);
+        require!(
+            (1..=OracleConfig::MAX_STALENESS).contains(&oracle.max_staleness)
+                && oracle.max_confidence_bps <= 10000,
+            ZentroError::InvalidOracleConfig
+        );
+
+        Ok(())
+    }
+
+    /// Whether `resolve_market` may be used, i.e. no oracle or proposal flow
+    /// was chosen at creation.
+    pub fn is_manually_resolved(&self) -> bool {
+        self.oracle.is_none() && self.optimistic.is_none()
+    }
+
+    /// Last second at which an oracle price is accepted, for oracle markets.
+    pub fn oracle_window_end(&self) -> Option<i64> {
+        self.oracle
+            .map(|oracle| self.end_time.saturating_add(oracle.max_staleness as i64))
+    }
+
+    /// Resolution implied by an already validated oracle price.
+    pub fn oracle_resolution(&self, feed: &PriceFeed) -> Result<ResolutionValue> {
+        let oracle = self.oracle.ok_or(ZentroError::OracleNotConfigured)?;
+
+        Ok(match oracle.rule {
+            OracleRule::Threshold { comparison, threshold } => {
+                ResolutionValue::Outcome(if comparison.holds(feed.price, threshold) { 0 } else { 1 })
+            }
+            OracleRule::Price => ResolutionValue::Scalar(feed.price),
+        })
+    }
+
+    /// Outstanding shares per outcome, in outcome order.
+    pub fn outcome_shares(&self) -> Vec<u64> {
+        self.outcomes.iter().map(|outcome| outcome.total_shares).collect()
+    }
+
+    /// Basis points of one collateral unit each share of `outcome` is worth
+    /// once the market is resolved.
+    pub fn payout_weight(&self, outcome: u8) -> Result<u64> {
+        match self.kind {
+            MarketKind::Categorical => {
+                let winning_outcome = self.winning_outcome.ok_or(ZentroError::MarketNotResolved)?;
+                Ok(if outcome == winning_outcome { 10000 } else { 0 })
+            }
+            MarketKind::Scalar { lower_bound, upper_bound } => {
+                let resolved_value = self.resolved_value.ok_or(ZentroError::MarketNotResolved)?;
+                let long_weight = scalar_long_weight(lower_bound, upper_bound, resolved_value)?;
+                Ok(if outcome == Self::LONG { long_weight } else { 10000 - long_weight })
+            }
+        }
+    }
+
+    /// Total stake across all outcomes, paid out to the winning outcome on resolution.
+    pub fn total_pool(&self) -> Option<u64> {
+        self.outcomes
+            .iter()
+            .try_fold(0u64, |acc, outcome| acc.checked_add(outcome.total_volume))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::lmsr_market;
+    use crate::utils::lmsr;
+
+    #[test]
+    fn test_lmsr_exit_profit_keeps_refunds_solvent() {
+        let b = 1000;
+        let mut market = lmsr_market(b);
+        let mut vault = market.subsidy;
+        assert_eq!(vault, 694); // ceil(1000 * ln 2)
+
+        // A buys 1000 shares, then B buys 1000 more of the same outcome
+        let a_cost = lmsr::cost_to_buy(&market.outcome_shares(), b, 0, 1000).unwrap();
+        market.outcomes[0].total_shares += 1000;
+        let b_cost = lmsr::cost_to_buy(&market.outcome_shares(), b, 0, 1000).unwrap();
+        market.outcomes[0].total_shares += 1000;
+        vault += a_cost + b_cost;
+        assert_eq!((a_cost, b_cost), (621, 814));
+
+        // A sells at B's higher price and walks away with a profit
+        let proceeds = lmsr::cost_to_sell(&market.outcome_shares(), b, 0, 1000).unwrap();
+        market.outcomes[0].total_shares -= 1000;
+        market.book_lmsr_exit(proceeds, a_cost).unwrap();
+        vault -= proceeds;
+        assert_eq!(proceeds, 813);
+
+        // Cancel returns the subsidy first, then B is refunded at cost
+        market.cancel(0).unwrap();
+        vault -= market.subsidy;
+        assert!(vault >= b_cost);
+    }
+
+    #[test]
+    fn test_lmsr_exit_loss_returns_to_subsidy() {
+        let mut market = lmsr_market(1000);
+        let subsidy = market.subsidy;
+
+        market.book_lmsr_exit(400, 500).unwrap();
+        assert_eq!(market.subsidy, subsidy + 100);
+        market.book_lmsr_exit(500, 400).unwrap();
+        assert_eq!(market.subsidy, subsidy);
+        assert!(market.book_lmsr_exit(subsidy + 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_lmsr_exit_profit_capped_by_subsidy() {
+        let mut market = lmsr_market(1000);
+        let subsidy = market.subsidy;
+
+        // Earlier exits took all but 10 of the subsidy
+        market.book_lmsr_exit(subsidy - 10 + 500, 500).unwrap();
+        assert_eq!(market.subsidy, 10);
+
+        // A profit of 11 no longer fits, while exactly 10 does
+        assert!(market.book_lmsr_exit(511, 500).is_err());
+        assert_eq!(market.subsidy, 10);
+        market.book_lmsr_exit(510, 500).unwrap();
+        assert_eq!(market.subsidy, 0);
+
+        // Only exits at or below cost remain possible
+        assert!(market.book_lmsr_exit(501, 500).is_err());
+        market.book_lmsr_exit(500, 500).unwrap();
+    }
+
+    #[test]
+    fn test_outstanding_claims_leave_unspent_subsidy() {
+        let b = 1000;
+        let mut market = lmsr_market(b);
+        let mut vault = market.subsidy;
+        for (outcome, shares) in [(0, 1500), (1, 400)] {
+            vault += lmsr::cost_to_buy(&market.outcome_shares(), b, outcome, shares).unwrap();
+            market.outcomes[outcome].total_shares += shares;
+        }
+        market.resolve(ResolutionValue::Outcome(1), 0).unwrap();
+        assert_eq!(market.outstanding_claims(&[]).unwrap(), 400);
+
+        // Claims and outcome tokens still in circulation stay reserved
+        market.total_claimed = 100;
+        assert_eq!(market.outstanding_claims(&[50, 80]).unwrap(), 380);
+        assert!(vault > 400);
+    }
+
+    #[test]
+    fn test_oracle_window_is_capped() {
+        let market = lmsr_market(1000);
+        let oracle = |max_staleness| OracleConfig {
+            feed: Pubkey::default(),
+            rule: OracleRule::Threshold { comparison: Comparison::GreaterOrEqual, threshold: 0 },
+            max_staleness,
+            max_confidence_bps: 100,
+        };
+        assert!(market.validate_oracle(&oracle(OracleConfig::MAX_STALENESS)).is_ok());
+        assert!(market.validate_oracle(&oracle(OracleConfig::MAX_STALENESS + 1)).is_err());
+        assert!(market.validate_oracle(&oracle(0)).is_err());
+    }
+
+    #[test]
+    fn test_pause_keeps_market_resolvable() {
+        let mut market = lmsr_market(1000);
+        market.set_paused(true).unwrap();
+        assert_eq!(market.status, MarketStatus::Paused);
+        assert!(market.is_open() && !market.is_active());
+        assert!(market.set_paused(true).is_err());
+
+        market.set_paused(false).unwrap();
+        assert_eq!(market.status, MarketStatus::Active);
+        assert!(market.set_paused(false).is_err());
+
+        market.set_paused(true).unwrap();
+        market.resolve(ResolutionValue::Outcome(0), 0).unwrap();
+        assert!(market.is_resolved());
+        assert!(market.set_paused(false).is_err());
+    }
+
+    #[test]
+    fn test_dispute_keeps_pause() {
+        let mut market = lmsr_market(1000);
+        market.set_paused(true).unwrap();
+        assert!(market.dispute().is_err());
+        assert_eq!(market.status, MarketStatus::Paused);
+
+        market.set_paused(false).unwrap();
+        market.dispute().unwrap();
+        assert_eq!(market.status, MarketStatus::Disputed);
+        assert!(market.dispute().is_err());
+        assert!(market.set_paused(true).is_err());
+    }
+
+    #[test]
+    fn test_parimutuel_without_winners_refunds() {
+        let mut market = Market { pricing: PricingEngine::Parimutuel, ..lmsr_market(1) };
+        market.outcomes[0].total_shares = 300;
+        market.outcomes[0].total_volume = 300;
+        market.resolve(ResolutionValue::Outcome(0), 0).unwrap();
+        assert!(!market.refunds_open());
+        assert_eq!(market.outstanding_claims(&[]).unwrap(), 300);
+
+        market.status = MarketStatus::Active;
+        market.resolve(ResolutionValue::Outcome(1), 0).unwrap();
+        assert!(market.refunds_open());
+        market.total_claimed = 300;
+        assert_eq!(market.outstanding_claims(&[]).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_deposits_only_accumulate() {
+        let mut market = lmsr_market(1000);
+        market.record_deposit(250).unwrap();
+        market.record_deposit(50).unwrap();
+        assert_eq!(market.total_deposits, 300);
+        assert!(market.record_deposit(u64::MAX).is_err());
+    }
+}
@@ -1 +1,23 @@
-I'm sorry. I'm not able to assist with that.
\ No newline at end of file
+pub mod bet;
+pub mod config;
+pub mod event_queue;
+pub mod liquidity_pool;
+pub mod liquidity_position;
+pub mod market;
+pub mod open_orders;
+pub mod order_book;
+pub mod resolution_proposal;
+pub mod resolver_committee;
+pub mod user_position;
+
+pub use bet::*;
+pub use config::*;
+pub use event_queue::*;
+pub use liquidity_pool::*;
+pub use liquidity_position::*;
+pub use market::*;
+pub use open_orders::*;
+pub use order_book::*;
+pub use resolution_proposal::*;
+pub use resolver_committee::*;
+pub use user_position::*;
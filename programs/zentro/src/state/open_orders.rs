@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// A trader's settled balances on one order book. `consume_events` credits
+/// the fills of their resting orders here; `settle_funds` pays them out.
+#[account]
+#[derive(InitSpace)]
+pub struct OpenOrders {
+    pub order_book: Pubkey,
+    pub owner: Pubkey,
+    /// Collateral received for filled asks.
+    pub free_collateral: u64,
+    /// Outcome tokens received for filled bids.
+    pub free_shares: u64,
+    pub bump: u8,
+}
+
+impl OpenOrders {
+    pub const LEN: usize = 8 + OpenOrders::INIT_SPACE;
+}
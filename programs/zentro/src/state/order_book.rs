@@ -0,0 +1,521 @@
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+
+/// Which side of the book an order rests on.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum Side {
+    /// Buys outcome tokens; escrows collateral.
+    Bid,
+    /// Sells outcome tokens; escrows the tokens.
+    Ask,
+}
+
+impl Side {
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::Bid => Side::Ask,
+            Side::Ask => Side::Bid,
+        }
+    }
+}
+
+/// Marks the end of a slab list.
+pub const NIL: u32 = u32::MAX;
+
+/// A resting limit order, stored as a node of the book's `Slab`.
+#[zero_copy]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Order {
+    pub owner: Pubkey,
+    pub order_id: u64,
+    /// Outcome tokens still open.
+    pub quantity: u64,
+    pub placed_at: i64,
+    /// Next order on the same side, or the next free node.
+    pub next: u32,
+    /// Collateral per outcome token, in basis points.
+    pub price: u16,
+    pub _padding: [u8; 2],
+}
+
+/// Fixed pool of order nodes shared by both sides of a book. Each side is a
+/// list kept best price first, in arrival order within a price; freed nodes
+/// are recycled through a free list, so placing and cancelling never moves
+/// other orders around.
+#[zero_copy]
+#[derive(Debug)]
+pub struct Slab {
+    pub bid_head: u32,
+    pub ask_head: u32,
+    pub free_head: u32,
+    /// Nodes handed out at least once; the ones past it were never used.
+    pub allocated: u32,
+    pub nodes: [Order; 128],
+}
+
+impl Slab {
+    pub const CAPACITY: usize = 128;
+
+    pub fn initialize(&mut self) {
+        self.bid_head = NIL;
+        self.ask_head = NIL;
+        self.free_head = NIL;
+        self.allocated = 0;
+    }
+
+    fn head(&self, side: Side) -> u32 {
+        match side {
+            Side::Bid => self.bid_head,
+            Side::Ask => self.ask_head,
+        }
+    }
+
+    fn set_head(&mut self, side: Side, index: u32) {
+        match side {
+            Side::Bid => self.bid_head = index,
+            Side::Ask => self.ask_head = index,
+        }
+    }
+
+    /// Orders on `side`, best first.
+    pub fn orders(&self, side: Side) -> impl Iterator<Item = &Order> {
+        let mut index = self.head(side);
+        std::iter::from_fn(move || {
+            let order = self.nodes.get(index as usize)?;
+            index = order.next;
+            Some(order)
+        })
+    }
+
+    fn alloc(&mut self) -> Result<u32> {
+        if self.free_head != NIL {
+            let index = self.free_head;
+            self.free_head = self.nodes[index as usize].next;
+            Ok(index)
+        } else {
+            require!((self.allocated as usize) < Self::CAPACITY, ZentroError::OrderBookFull);
+            self.allocated += 1;
+            Ok(self.allocated - 1)
+        }
+    }
+
+    /// Links `order` in behind every order at the same or a better price.
+    pub fn insert(&mut self, side: Side, order: Order) -> Result<()> {
+        let index = self.alloc()?;
+
+        let mut prev = NIL;
+        let mut cur = self.head(side);
+        while cur != NIL {
+            let resting = &self.nodes[cur as usize];
+            let worse = match side {
+                Side::Bid => resting.price < order.price,
+                Side::Ask => resting.price > order.price,
+            };
+            if worse {
+                break;
+            }
+            prev = cur;
+            cur = resting.next;
+        }
+
+        self.nodes[index as usize] = Order { next: cur, ..order };
+        if prev == NIL {
+            self.set_head(side, index);
+        } else {
+            self.nodes[prev as usize].next = index;
+        }
+
+        Ok(())
+    }
+
+    /// Unlinks the node after `prev` (the head when `prev` is `NIL`) and
+    /// returns it to the free list.
+    fn unlink(&mut self, side: Side, prev: u32, index: u32) -> Order {
+        let order = self.nodes[index as usize];
+        if prev == NIL {
+            self.set_head(side, order.next);
+        } else {
+            self.nodes[prev as usize].next = order.next;
+        }
+        self.nodes[index as usize].next = self.free_head;
+        self.free_head = index;
+        order
+    }
+
+    fn locate(&self, order_id: u64) -> Option<(Side, u32, u32)> {
+        [Side::Bid, Side::Ask].into_iter().find_map(|side| {
+            let mut prev = NIL;
+            let mut cur = self.head(side);
+            while cur != NIL {
+                if self.nodes[cur as usize].order_id == order_id {
+                    return Some((side, prev, cur));
+                }
+                prev = cur;
+                cur = self.nodes[cur as usize].next;
+            }
+            None
+        })
+    }
+
+    pub fn find(&self, order_id: u64) -> Option<(Side, Order)> {
+        self.locate(order_id)
+            .map(|(side, _, index)| (side, self.nodes[index as usize]))
+    }
+
+    pub fn remove(&mut self, order_id: u64) -> Option<(Side, Order)> {
+        let (side, prev, index) = self.locate(order_id)?;
+        Some((side, self.unlink(side, prev, index)))
+    }
+
+    /// Orders resting on `side`.
+    pub fn count(&self, side: Side) -> usize {
+        self.orders(side).count()
+    }
+
+    /// Whether every node is holding an order.
+    pub fn is_full(&self) -> bool {
+        self.free_head == NIL && self.allocated as usize == Self::CAPACITY
+    }
+
+    /// Unlinks the worst order on `side`: the one furthest from the top, and
+    /// the newest at that price.
+    fn pop_worst(&mut self, side: Side) -> Option<Order> {
+        let mut prev = NIL;
+        let mut cur = self.head(side);
+        if cur == NIL {
+            return None;
+        }
+        while self.nodes[cur as usize].next != NIL {
+            prev = cur;
+            cur = self.nodes[cur as usize].next;
+        }
+        Some(self.unlink(side, prev, cur))
+    }
+
+    /// Orders `owner` has resting on either side.
+    pub fn orders_of(&self, owner: &Pubkey) -> usize {
+        self.orders(Side::Bid)
+            .chain(self.orders(Side::Ask))
+            .filter(|order| order.owner == *owner)
+            .count()
+    }
+}
+
+/// One match of an incoming order against a resting one, priced at the
+/// resting order's price.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fill {
+    pub order_id: u64,
+    pub maker: Pubkey,
+    pub price: u16,
+    pub quantity: u64,
+    /// Collateral that changes hands for `quantity` tokens.
+    pub collateral: u64,
+}
+
+/// Limit order book for one outcome token of a market. Orders live in the
+/// slab; fills owed to resting orders go through the book's `EventQueue`.
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct OrderBook {
+    pub market: Pubkey,
+    pub token_mint: Pubkey,
+    pub outcome_mint: Pubkey,
+    /// Holds the collateral escrowed by resting bids and owed to filled asks.
+    pub collateral_vault: Pubkey,
+    /// Holds the outcome tokens escrowed by resting asks and owed to filled bids.
+    pub share_vault: Pubkey,
+    pub event_queue: Pubkey,
+    pub next_order_id: u64,
+    /// Lamports a taker pays per fill, copied from `Config` at creation.
+    pub crank_fee: u64,
+    /// Smallest collateral value an order may be placed or rest with,
+    /// copied from `Config` at creation.
+    pub min_order_notional: u64,
+    pub outcome: u8,
+    pub bump: u8,
+    pub _padding: [u8; 6],
+    pub slab: Slab,
+}
+
+impl OrderBook {
+    pub const LEN: usize = 8 + std::mem::size_of::<OrderBook>();
+
+    /// Valid limit prices, in basis points.
+    pub const MIN_PRICE: u16 = 1;
+    pub const MAX_PRICE: u16 = 9999;
+
+    /// Resting orders one owner may keep on a book. Fresh keys get around
+    /// it, so a full slab is handled by eviction in `insert`.
+    pub const MAX_ORDERS_PER_OWNER: usize = 8;
+
+    /// Collateral a bid for `quantity` tokens at `price` escrows, rounded up.
+    pub fn bid_escrow(quantity: u64, price: u16) -> Result<u64> {
+        let escrow = (quantity as u128)
+            .checked_mul(price as u128)
+            .ok_or(ZentroError::MathOverflow)?
+            .div_ceil(10000);
+
+        u64::try_from(escrow).map_err(|_| ZentroError::MathOverflow.into())
+    }
+
+    /// Matches an incoming order against the opposite side in price-time
+    /// priority and returns the fills with the quantity left unmatched.
+    /// Fails if the order would cross more than `max_fills` resting orders.
+    ///
+    /// An incoming bid pays each ask its price rounded up. An incoming ask
+    /// receives the part of the bid's escrow its tokens release, so a fully
+    /// filled bid leaves nothing behind in the vault.
+    pub fn match_order(
+        &mut self,
+        side: Side,
+        price: u16,
+        quantity: u64,
+        max_fills: usize,
+    ) -> Result<(Vec<Fill>, u64)> {
+        let mut fills = Vec::new();
+        let mut remaining = quantity;
+        let resting_side = side.opposite();
+
+        while remaining > 0 {
+            let index = self.slab.head(resting_side);
+            if index == NIL {
+                break;
+            }
+            let maker = self.slab.nodes[index as usize];
+            let crosses = match side {
+                Side::Bid => maker.price <= price,
+                Side::Ask => maker.price >= price,
+            };
+            if !crosses {
+                break;
+            }
+            require!(fills.len() < max_fills, ZentroError::EventQueueFull);
+
+            let quantity = remaining.min(maker.quantity);
+            let collateral = match side {
+                Side::Bid => Self::bid_escrow(quantity, maker.price)?,
+                Side::Ask => Self::bid_escrow(maker.quantity, maker.price)?
+                    .checked_sub(Self::bid_escrow(maker.quantity - quantity, maker.price)?)
+                    .ok_or(ZentroError::MathOverflow)?,
+            };
+            fills.push(Fill {
+                order_id: maker.order_id,
+                maker: maker.owner,
+                price: maker.price,
+                quantity,
+                collateral,
+            });
+
+            remaining -= quantity;
+            if quantity == maker.quantity {
+                self.slab.unlink(resting_side, NIL, index);
+            } else {
+                self.slab.nodes[index as usize].quantity -= quantity;
+            }
+        }
+
+        Ok((fills, remaining))
+    }
+
+    /// Rests a new order on the book and returns its id. Fails once `owner`
+    /// already has `MAX_ORDERS_PER_OWNER` orders resting.
+    ///
+    /// When the slab is full, the worst order on the side holding more of it
+    /// is evicted and returned so its escrow can be handed back. An order
+    /// joining that side (or either side, when both hold half) must be
+    /// priced strictly better than the order it evicts, so parking orders
+    /// away from the market never keeps a competitive one out.
+    pub fn insert(
+        &mut self,
+        side: Side,
+        owner: Pubkey,
+        price: u16,
+        quantity: u64,
+        placed_at: i64,
+    ) -> Result<(u64, Option<(Side, Order)>)> {
+        require!(
+            self.slab.orders_of(&owner) < Self::MAX_ORDERS_PER_OWNER,
+            ZentroError::TooManyOpenOrders
+        );
+
+        let evicted = if self.slab.is_full() {
+            let bids = self.slab.count(Side::Bid);
+            let victim_side = match bids.cmp(&(Slab::CAPACITY - bids)) {
+                std::cmp::Ordering::Greater => Side::Bid,
+                std::cmp::Ordering::Less => Side::Ask,
+                std::cmp::Ordering::Equal => side,
+            };
+            if victim_side == side {
+                let worst = self.slab.orders(side).last().ok_or(ZentroError::OrderBookFull)?;
+                let better = match side {
+                    Side::Bid => price > worst.price,
+                    Side::Ask => price < worst.price,
+                };
+                require!(better, ZentroError::OrderBookFull);
+            }
+            let order = self.slab.pop_worst(victim_side).ok_or(ZentroError::OrderBookFull)?;
+            Some((victim_side, order))
+        } else {
+            None
+        };
+
+        let order_id = self.next_order_id;
+        self.next_order_id = order_id.checked_add(1).ok_or(ZentroError::MathOverflow)?;
+
+        self.slab.insert(
+            side,
+            Order {
+                owner,
+                order_id,
+                quantity,
+                placed_at,
+                next: NIL,
+                price,
+                _padding: [0; 2],
+            },
+        )?;
+
+        Ok((order_id, evicted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::order_book;
+
+    fn rest(book: &mut OrderBook, side: Side, price: u16, quantity: u64) -> u64 {
+        book.insert(side, Pubkey::new_unique(), price, quantity, 0).unwrap().0
+    }
+
+    fn ids(book: &OrderBook, side: Side) -> Vec<u64> {
+        book.slab.orders(side).map(|order| order.order_id).collect()
+    }
+
+    #[test]
+    fn test_price_time_priority() {
+        let mut book = order_book();
+        let first = rest(&mut book, Side::Ask, 6000, 100);
+        let cheaper = rest(&mut book, Side::Ask, 5500, 100);
+        let second = rest(&mut book, Side::Ask, 6000, 100);
+        let bid = rest(&mut book, Side::Bid, 5000, 100);
+
+        assert_eq!(ids(&book, Side::Ask), vec![cheaper, first, second]);
+
+        // A bid at 6000 for 250 sweeps the cheaper ask, then the older 6000 ask
+        let (fills, remaining) = book.match_order(Side::Bid, 6000, 250, 8).unwrap();
+        assert_eq!(remaining, 0);
+        assert_eq!(fills.len(), 3);
+        assert_eq!((fills[0].order_id, fills[0].quantity, fills[0].collateral), (cheaper, 100, 55));
+        assert_eq!((fills[1].order_id, fills[1].quantity, fills[1].collateral), (first, 100, 60));
+        assert_eq!((fills[2].order_id, fills[2].quantity, fills[2].collateral), (second, 50, 30));
+        assert_eq!(ids(&book, Side::Ask), vec![second]);
+        assert_eq!(book.slab.find(second).unwrap().1.quantity, 50);
+
+        // Nothing crosses below the best ask; the bids are untouched
+        let (fills, remaining) = book.match_order(Side::Bid, 5999, 10, 8).unwrap();
+        assert!(fills.is_empty());
+        assert_eq!(remaining, 10);
+        assert_eq!(ids(&book, Side::Bid), vec![bid]);
+    }
+
+    #[test]
+    fn test_match_respects_max_fills() {
+        let mut book = order_book();
+        rest(&mut book, Side::Bid, 5000, 10);
+        rest(&mut book, Side::Bid, 4000, 10);
+
+        assert!(book.match_order(Side::Ask, 4000, 15, 1).is_err());
+        let (fills, remaining) = book.match_order(Side::Ask, 4000, 10, 1).unwrap();
+        assert_eq!((fills.len(), remaining), (1, 0));
+    }
+
+    #[test]
+    fn test_bid_escrow_fully_released() {
+        let mut book = order_book();
+        let price = 3333;
+        let escrow = OrderBook::bid_escrow(7, price).unwrap();
+        assert_eq!(escrow, 3);
+        rest(&mut book, Side::Bid, price, 7);
+
+        // Sold one token at a time, the bid hands out exactly its escrow
+        let mut paid = 0;
+        for _ in 0..7 {
+            let (fills, remaining) = book.match_order(Side::Ask, price, 1, 1).unwrap();
+            assert_eq!(remaining, 0);
+            paid += fills[0].collateral;
+        }
+        assert_eq!(paid, escrow);
+        assert_eq!(book.slab.orders(Side::Bid).count(), 0);
+    }
+
+    #[test]
+    fn test_remove_and_capacity() {
+        let mut book = order_book();
+        for _ in 0..Slab::CAPACITY - 1 {
+            rest(&mut book, Side::Bid, 4000, 1);
+        }
+        let ask = rest(&mut book, Side::Ask, 4500, 1);
+        assert!(book.insert(Side::Bid, Pubkey::new_unique(), 4000, 1, 0).is_err());
+
+        assert_eq!(book.slab.remove(ask).map(|(side, order)| (side, order.quantity)), Some((Side::Ask, 1)));
+        assert!(book.slab.remove(ask).is_none());
+        assert!(book.slab.remove(3).is_some());
+        assert_eq!(book.slab.orders(Side::Bid).count(), Slab::CAPACITY - 2);
+
+        // Freed nodes are reused
+        rest(&mut book, Side::Ask, 4500, 1);
+        rest(&mut book, Side::Bid, 4100, 1);
+        assert_eq!(book.slab.orders(Side::Bid).next().unwrap().price, 4100);
+        assert!(book.insert(Side::Bid, Pubkey::new_unique(), 4000, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_orders_per_owner_capped() {
+        let mut book = order_book();
+        let owner = Pubkey::new_unique();
+        let mut last = 0;
+        for i in 0..OrderBook::MAX_ORDERS_PER_OWNER {
+            let side = if i % 2 == 0 { Side::Bid } else { Side::Ask };
+            let price = if side == Side::Bid { 4000 } else { 6000 };
+            last = book.insert(side, owner, price, 1, 0).unwrap().0;
+        }
+        assert_eq!(book.slab.orders_of(&owner), OrderBook::MAX_ORDERS_PER_OWNER);
+        assert!(book.insert(Side::Bid, owner, 4000, 1, 0).is_err());
+        rest(&mut book, Side::Bid, 4000, 1);
+
+        // A filled or cancelled order frees a slot
+        book.slab.remove(last).unwrap();
+        book.insert(Side::Bid, owner, 4000, 1, 0).unwrap();
+        let (fills, _) = book.match_order(Side::Ask, 4000, 1, 1).unwrap();
+        assert_eq!(fills[0].maker, owner);
+        book.insert(Side::Bid, owner, 4000, 1, 0).unwrap();
+    }
+
+    #[test]
+    fn test_full_book_evicts_worst_order() {
+        let mut book = order_book();
+        // Parked far from the market, asks take more than half the slab
+        for _ in 0..Slab::CAPACITY - 28 {
+            rest(&mut book, Side::Ask, 9999, 1);
+        }
+        for _ in 0..28 {
+            rest(&mut book, Side::Bid, 4000, 1);
+        }
+        let newest = *book.slab.orders(Side::Ask).last().unwrap();
+
+        // A bid still rests, pushing out the newest ask at the worst price
+        let (bid, evicted) = book.insert(Side::Bid, Pubkey::new_unique(), 100, 1, 0).unwrap();
+        assert_eq!(evicted, Some((Side::Ask, newest)));
+        assert_eq!(*ids(&book, Side::Bid).last().unwrap(), bid);
+        assert_eq!(book.slab.count(Side::Ask), Slab::CAPACITY - 29);
+
+        // An ask joining the fuller side has to beat its worst order
+        assert!(book.insert(Side::Ask, Pubkey::new_unique(), 9999, 1, 0).is_err());
+        let (_, evicted) = book.insert(Side::Ask, Pubkey::new_unique(), 9998, 1, 0).unwrap();
+        assert_eq!(evicted.map(|(side, order)| (side, order.price)), Some((Side::Ask, 9999)));
+        assert_eq!(book.slab.orders(Side::Ask).next().unwrap().price, 9998);
+        assert!(book.slab.is_full());
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::state::ResolutionValue;
+
+/// Bonded claim about how a market resolved. The proposer's bond, and the
+/// disputer's once challenged, are held in this account's lamports.
+#[account]
+#[derive(InitSpace)]
+pub struct ResolutionProposal {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub resolution: ResolutionValue,
+    /// Posted by each side; a dispute doubles the lamports held.
+    pub bond: u64,
+    pub proposed_at: i64,
+    pub challenge_ends_at: i64,
+    pub disputer: Option<Pubkey>,
+    pub disputed_at: i64,
+    /// Last second committee votes are accepted, set when disputed from
+    /// `Config::dispute_timeout`.
+    pub voting_ends_at: i64,
+    /// Committee votes cast on the dispute, one per member.
+    #[max_len(16)]
+    pub votes: Vec<ResolverVote>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct ResolverVote {
+    pub member: Pubkey,
+    pub resolution: ResolutionValue,
+}
+
+impl ResolutionProposal {
+    pub fn is_disputed(&self) -> bool {
+        self.disputer.is_some()
+    }
+
+    pub fn has_voted(&self, member: &Pubkey) -> bool {
+        self.votes.iter().any(|vote| vote.member == *member)
+    }
+
+    pub fn votes_for(&self, resolution: ResolutionValue) -> usize {
+        self.votes.iter().filter(|vote| vote.resolution == resolution).count()
+    }
+
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        now <= self.voting_ends_at
+    }
+
+    /// Whether voting closed without any resolution reaching `threshold`,
+    /// e.g. after a split vote or silent members; the dispute can then only
+    /// end in cancellation.
+    pub fn is_deadlocked(&self, threshold: u8, now: i64) -> bool {
+        !self.is_voting_open(now) && self.committee_decision(threshold).is_none()
+    }
+
+    /// The resolution backed by at least `threshold` votes, if any.
+    pub fn committee_decision(&self, threshold: u8) -> Option<ResolutionValue> {
+        self.votes
+            .iter()
+            .map(|vote| vote.resolution)
+            .find(|resolution| self.votes_for(*resolution) >= threshold as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::proposal;
+
+    #[test]
+    fn test_committee_decision() {
+        let mut proposal = proposal();
+        let mut vote = |resolution| {
+            proposal.votes.push(ResolverVote { member: Pubkey::new_unique(), resolution });
+        };
+        vote(ResolutionValue::Outcome(1));
+        vote(ResolutionValue::Outcome(0));
+        vote(ResolutionValue::Outcome(1));
+
+        assert_eq!(proposal.votes_for(ResolutionValue::Outcome(1)), 2);
+        assert_eq!(proposal.committee_decision(2), Some(ResolutionValue::Outcome(1)));
+        assert_eq!(proposal.committee_decision(3), None);
+    }
+
+    #[test]
+    fn test_split_vote_deadlocks_after_deadline() {
+        let mut proposal = ResolutionProposal {
+            disputer: Some(Pubkey::default()),
+            voting_ends_at: 100,
+            ..proposal()
+        };
+        // Five members with threshold 3 split 2/2/1
+        for resolution in [0, 1, 0, 1, 2] {
+            proposal.votes.push(ResolverVote {
+                member: Pubkey::new_unique(),
+                resolution: ResolutionValue::Outcome(resolution),
+            });
+        }
+
+        assert_eq!(proposal.committee_decision(3), None);
+        assert!(!proposal.is_deadlocked(3, 100));
+        assert!(proposal.is_deadlocked(3, 101));
+        // A decided dispute is settled, never deadlocked
+        assert!(!proposal.is_deadlocked(2, 101));
+    }
+}
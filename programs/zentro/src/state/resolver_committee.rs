@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+
+/// M-of-N panel that arbitrates disputed resolution proposals of the markets
+/// that name it in their `OptimisticConfig`.
+#[account]
+#[derive(InitSpace)]
+pub struct ResolverCommittee {
+    pub authority: Pubkey,
+    pub committee_id: u64,
+    #[max_len(16)]
+    pub members: Vec<Pubkey>,
+    /// Matching votes needed to settle a dispute; more than half the members.
+    pub threshold: u8,
+    pub bump: u8,
+}
+
+impl ResolverCommittee {
+    pub const MAX_MEMBERS: usize = 16;
+
+    pub fn validate(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= Self::MAX_MEMBERS,
+            ZentroError::InvalidCommitteeConfig
+        );
+        // A strict majority, so two different resolutions can never both pass
+        require!(
+            threshold as usize * 2 > members.len() && threshold as usize <= members.len(),
+            ZentroError::InvalidCommitteeConfig
+        );
+        require!(
+            members
+                .iter()
+                .enumerate()
+                .all(|(i, member)| !members[..i].contains(member)),
+            ZentroError::InvalidCommitteeConfig
+        );
+
+        Ok(())
+    }
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_committee() {
+        let members: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        assert!(ResolverCommittee::validate(&members, 2).is_ok());
+        assert!(ResolverCommittee::validate(&members, 3).is_ok());
+        assert!(ResolverCommittee::validate(&members, 0).is_err());
+        assert!(ResolverCommittee::validate(&members, 1).is_err());
+        assert!(ResolverCommittee::validate(&members[..2], 1).is_err());
+        assert!(ResolverCommittee::validate(&members[..2], 2).is_ok());
+        assert!(ResolverCommittee::validate(&members, 4).is_err());
+        assert!(ResolverCommittee::validate(&[], 1).is_err());
+        assert!(ResolverCommittee::validate(&[members[0], members[1], members[0]], 2).is_err());
+    }
+}
@@ -1,6 +1,8 @@
-```rust
 use anchor_lang::prelude::*;
+use crate::error::ZentroError;
 
+/// Holding of one user in one outcome. Prices are in basis points of one
+/// collateral unit per share, matching `utils::pricing`.
 #[account]
 pub struct UserPosition {
     pub user: Pubkey,
@@ -9,9 +11,12 @@ pub struct UserPosition {
     pub shares: u64,
     pub average_price: u64,
     pub total_invested: u64,
+    /// Proceeds of shares sold back before resolution minus their cost basis.
+    pub realized_pnl: i64,
     pub created_at: i64,
     pub last_updated: i64,
     pub is_active: bool,
+    pub claimed: bool,
     pub bump: u8,
 }
 
@@ -23,9 +28,11 @@ impl UserPosition {
         8 + // shares
         8 + // average_price
         8 + // total_invested
+        8 + // realized_pnl
         8 + // created_at
         8 + // last_updated
         1 + // is_active
+        1 + // claimed
         1; // bump
 
     pub fn new(
@@ -33,77 +40,114 @@ impl UserPosition {
         market: Pubkey,
         outcome: u8,
         shares: u64,
-        price: u64,
+        cost: u64,
         bump: u8,
-    ) -> Self {
-        let clock = Clock::get().unwrap();
-        let total_invested = shares.checked_mul(price).unwrap();
+    ) -> Result<Self> {
+        let clock = Clock::get()?;
         
-        Self {
+        Ok(Self {
             user,
             market,
             outcome,
             shares,
-            average_price: price,
-            total_invested,
+            average_price: Self::price_of(cost, shares)?,
+            total_invested: cost,
+            realized_pnl: 0,
             created_at: clock.unix_timestamp,
             last_updated: clock.unix_timestamp,
             is_active: true,
+            claimed: false,
             bump,
-        }
+        })
+    }
+
+    /// Basis-point price per share paid for `shares` shares costing `cost` in total.
+    pub fn price_of(cost: u64, shares: u64) -> Result<u64> {
+        let price = (cost as u128)
+            .checked_mul(10000)
+            .ok_or(ZentroError::MathOverflow)?
+            .checked_div(shares as u128)
+            .ok_or(ZentroError::MathOverflow)?;
+
+        u64::try_from(price).map_err(|_| ZentroError::MathOverflow.into())
     }
 
-    pub fn add_shares(&mut self, additional_shares: u64, price: u64) -> Result<()> {
-        let additional_investment = additional_shares.checked_mul(price).unwrap();
-        let new_total_invested = self.total_invested.checked_add(additional_investment).unwrap();
-        let new_total_shares = self.shares.checked_add(additional_shares).unwrap();
+    pub fn add_shares(&mut self, additional_shares: u64, cost: u64) -> Result<()> {
+        let new_total_invested = self.total_invested.checked_add(cost)
+            .ok_or(ZentroError::MathOverflow)?;
+        let new_total_shares = self.shares.checked_add(additional_shares)
+            .ok_or(ZentroError::MathOverflow)?;
         
-        self.average_price = new_total_invested.checked_div(new_total_shares).unwrap();
+        self.average_price = Self::price_of(new_total_invested, new_total_shares)?;
         self.shares = new_total_shares;
         self.total_invested = new_total_invested;
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        // A position sold down to zero is reused when bought into again
+        self.is_active = true;
+        self.last_updated = Clock::get()?.unix_timestamp;
         
         Ok(())
     }
 
-    pub fn remove_shares(&mut self, shares_to_remove: u64) -> Result<()> {
-        require!(self.shares >= shares_to_remove, ErrorCode::InsufficientShares);
+    /// Removes `shares_to_remove` shares and returns the cost basis that
+    /// leaves with them.
+    pub fn remove_shares(&mut self, shares_to_remove: u64) -> Result<u64> {
+        require!(self.shares >= shares_to_remove, ZentroError::InsufficientShares);
         
-        let remaining_shares = self.shares.checked_sub(shares_to_remove).unwrap();
+        let remaining_shares = self.shares.checked_sub(shares_to_remove)
+            .ok_or(ZentroError::MathOverflow)?;
         
-        if remaining_shares == 0 {
+        let investment_to_remove = if remaining_shares == 0 {
             self.is_active = false;
-            self.shares = 0;
-            self.total_invested = 0;
+            self.total_invested
         } else {
-            let investment_to_remove = shares_to_remove.checked_mul(self.average_price).unwrap();
-            self.shares = remaining_shares;
-            self.total_invested = self.total_invested.checked_sub(investment_to_remove).unwrap();
-        }
+            // Cost basis leaves in proportion to the shares removed
+            (self.total_invested as u128)
+                .checked_mul(shares_to_remove as u128)
+                .ok_or(ZentroError::MathOverflow)?
+                .checked_div(self.shares as u128)
+                .ok_or(ZentroError::MathOverflow)? as u64
+        };
+        self.shares = remaining_shares;
+        self.total_invested = self.total_invested.checked_sub(investment_to_remove)
+            .ok_or(ZentroError::MathOverflow)?;
         
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        self.last_updated = Clock::get()?.unix_timestamp;
         
-        Ok(())
+        Ok(investment_to_remove)
     }
 
-    pub fn calculate_pnl(&self, current_price: u64) -> i64 {
-        let current_value = self.shares.checked_mul(current_price).unwrap();
-        current_value as i64 - self.total_invested as i64
+    /// Sells `shares` back for `proceeds` and books the realized profit or
+    /// loss against their cost basis. Returns the PnL of this sale.
+    pub fn sell_shares(&mut self, shares: u64, proceeds: u64) -> Result<i64> {
+        let cost_basis = self.remove_shares(shares)?;
+        let pnl = i64::try_from(proceeds as i128 - cost_basis as i128)
+            .map_err(|_| ZentroError::MathOverflow)?;
+
+        self.realized_pnl = self.realized_pnl.checked_add(pnl)
+            .ok_or(ZentroError::MathOverflow)?;
+
+        Ok(pnl)
+    }
+
+    pub fn calculate_pnl(&self, current_price: u64) -> Result<i64> {
+        let current_value = (self.shares as u128)
+            .checked_mul(current_price as u128)
+            .ok_or(ZentroError::MathOverflow)?
+            / 10000;
+        let current_value = i64::try_from(current_value).map_err(|_| ZentroError::MathOverflow)?;
+        let total_invested = i64::try_from(self.total_invested).map_err(|_| ZentroError::MathOverflow)?;
+
+        current_value
+            .checked_sub(total_invested)
+            .ok_or(ZentroError::MathOverflow.into())
     }
 
-    pub fn calculate_roi(&self, current_price: u64) -> f64 {
+    pub fn calculate_roi(&self, current_price: u64) -> Result<f64> {
         if self.total_invested == 0 {
-            return 0.0;
+            return Ok(0.0);
         }
         
-        let pnl = self.calculate_pnl(current_price);
-        (pnl as f64 / self.total_invested as f64) * 100.0
+        let pnl = self.calculate_pnl(current_price)?;
+        Ok((pnl as f64 / self.total_invested as f64) * 100.0)
     }
 }
-
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Insufficient shares to remove")]
-    InsufficientShares,
-}
-```
\ No newline at end of file
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+
+// Logarithmic market scoring rule (Hanson). The market maker's cost function is
+//
+//     C(q) = b * ln(sum_i exp(q_i / b))
+//
+// where q_i is the number of outstanding shares of outcome i and b is the
+// liquidity parameter. A trade moving the book from q to q' costs C(q') - C(q),
+// so prices are path independent, and the market maker can lose at most
+// b * ln(n), which the creator funds up front.
+//
+// All arithmetic is done in u128 fixed point with 12 decimals so results are
+// identical on every validator.
+
+/// Fixed-point 1.0
+pub const ONE: u128 = 1_000_000_000_000;
+
+/// ln(2) in fixed point
+const LN_2: u128 = 693_147_180_560;
+
+/// exp(-x) for fixed-point x >= 0.
+pub fn exp_neg(x: u128) -> u128 {
+    // x = k * ln2 + r with 0 <= r < ln2, so exp(-x) = 2^-k / exp(r)
+    let k = x / LN_2;
+    if k >= 64 {
+        return 0;
+    }
+    let r = x % LN_2;
+
+    // Taylor series of exp(r); every term is positive and r < 0.7 converges fast
+    let mut term = ONE;
+    let mut sum = ONE;
+    let mut n = 1;
+    while term > 0 {
+        term = term * r / ONE / n;
+        sum += term;
+        n += 1;
+    }
+
+    (ONE * ONE / sum) >> k
+}
+
+/// Natural log of fixed-point y >= 1.
+pub fn ln(y: u128) -> Result<u128> {
+    require!(y >= ONE, ZentroError::MathOverflow);
+
+    // y = 2^k * m with 1 <= m < 2
+    let k = (y / ONE).ilog2();
+    let m = y >> k;
+
+    // ln(m) = 2 * atanh(z) with z = (m - 1) / (m + 1) < 1/3
+    let z = (m - ONE) * ONE / (m + ONE);
+    let z_squared = z * z / ONE;
+    let mut power = z;
+    let mut sum = 0;
+    let mut n = 1;
+    while power > 0 {
+        sum += power / n;
+        power = power * z_squared / ONE;
+        n += 2;
+    }
+
+    Ok(k as u128 * LN_2 + 2 * sum)
+}
+
+/// C(q) in fixed point, evaluated as max(q) + b * ln(sum_i exp((q_i - max(q)) / b))
+/// so every exponent is non-positive.
+pub fn cost(quantities: &[u64], liquidity: u64) -> Result<u128> {
+    require!(liquidity > 0, ZentroError::InvalidLiquidityParameter);
+    require!(!quantities.is_empty(), ZentroError::InvalidOutcomeCount);
+
+    let max = *quantities.iter().max().unwrap_or(&0);
+    let sum = quantities
+        .iter()
+        .map(|q| exp_neg((max - q) as u128 * ONE / liquidity as u128))
+        .sum::<u128>();
+
+    let log_sum = ln(sum)?;
+
+    (max as u128)
+        .checked_mul(ONE)
+        .and_then(|base| base.checked_add(liquidity as u128 * log_sum))
+        .ok_or(ZentroError::MathOverflow.into())
+}
+
+/// Collateral charged to buy `shares` of `outcome`, rounded up in favor of the market.
+pub fn cost_to_buy(quantities: &[u64], liquidity: u64, outcome: usize, shares: u64) -> Result<u64> {
+    require!(outcome < quantities.len(), ZentroError::InvalidOutcome);
+
+    let mut after = quantities.to_vec();
+    after[outcome] = after[outcome]
+        .checked_add(shares)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    let delta = cost(&after, liquidity)?
+        .checked_sub(cost(quantities, liquidity)?)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    u64::try_from(delta.div_ceil(ONE)).map_err(|_| ZentroError::MathOverflow.into())
+}
+
+/// Collateral returned for selling `shares` of `outcome`, rounded down in favor of the market.
+pub fn cost_to_sell(quantities: &[u64], liquidity: u64, outcome: usize, shares: u64) -> Result<u64> {
+    require!(outcome < quantities.len(), ZentroError::InvalidOutcome);
+
+    let mut after = quantities.to_vec();
+    after[outcome] = after[outcome]
+        .checked_sub(shares)
+        .ok_or(ZentroError::InsufficientShares)?;
+
+    let delta = cost(quantities, liquidity)?
+        .checked_sub(cost(&after, liquidity)?)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    u64::try_from(delta / ONE).map_err(|_| ZentroError::MathOverflow.into())
+}
+
+/// Instantaneous price of `outcome` in basis points.
+pub fn price(quantities: &[u64], liquidity: u64, outcome: usize) -> Result<u64> {
+    require!(liquidity > 0, ZentroError::InvalidLiquidityParameter);
+    require!(outcome < quantities.len(), ZentroError::InvalidOutcome);
+
+    let max = *quantities.iter().max().unwrap_or(&0);
+    let weights: Vec<u128> = quantities
+        .iter()
+        .map(|q| exp_neg((max - q) as u128 * ONE / liquidity as u128))
+        .collect();
+    let sum: u128 = weights.iter().sum();
+
+    Ok((weights[outcome] * 10000 / sum) as u64)
+}
+
+/// Worst-case loss of the market maker, b * ln(n), rounded up. The creator
+/// deposits this much when opening an LMSR market.
+pub fn max_subsidy_loss(liquidity: u64, outcome_count: usize) -> Result<u64> {
+    require!(liquidity > 0, ZentroError::InvalidLiquidityParameter);
+    require!(outcome_count > 0, ZentroError::InvalidOutcomeCount);
+
+    let loss = (liquidity as u128)
+        .checked_mul(ln(outcome_count as u128 * ONE)?)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    u64::try_from(loss.div_ceil(ONE)).map_err(|_| ZentroError::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_f64(x: u128) -> f64 {
+        x as f64 / ONE as f64
+    }
+
+    #[test]
+    fn test_exp_neg() {
+        assert_eq!(exp_neg(0), ONE);
+        for x in [0.1, 0.5, 1.0, 2.5, 10.0, 30.0] {
+            let fixed = to_f64(exp_neg((x * ONE as f64) as u128));
+            assert!((fixed - (-x).exp()).abs() < 1e-9, "exp(-{x})");
+        }
+        assert_eq!(exp_neg(100 * ONE), 0);
+    }
+
+    #[test]
+    fn test_ln() {
+        assert_eq!(ln(ONE).unwrap(), 0);
+        for y in [1.5, 2.0, 3.0, 7.99, 1000.0] {
+            let fixed = to_f64(ln((y * ONE as f64) as u128).unwrap());
+            assert!((fixed - y.ln()).abs() < 1e-9, "ln({y})");
+        }
+        assert!(ln(ONE / 2).is_err());
+    }
+
+    #[test]
+    fn test_initial_price_is_uniform() {
+        assert_eq!(price(&[0, 0], 1000, 0).unwrap(), 5000);
+        assert_eq!(price(&[0, 0, 0, 0], 1000, 3).unwrap(), 2500);
+    }
+
+    #[test]
+    fn test_cost_to_buy_matches_closed_form() {
+        // Two outcomes from zero: b * ln((exp(s / b) + 1) / 2)
+        let (b, s) = (1_000_000u64, 500_000u64);
+        let expected = b as f64 * (((s as f64 / b as f64).exp() + 1.0) / 2.0).ln();
+        let charged = cost_to_buy(&[0, 0], b, 0, s).unwrap();
+        assert!((charged as f64 - expected).abs() <= 1.0);
+        assert!(charged as f64 >= expected);
+    }
+
+    #[test]
+    fn test_path_independence() {
+        let b = 1_000_000;
+        let split = cost_to_buy(&[0, 0, 0], b, 1, 300_000).unwrap()
+            + cost_to_buy(&[0, 300_000, 0], b, 1, 200_000).unwrap();
+        let single = cost_to_buy(&[0, 0, 0], b, 1, 500_000).unwrap();
+        assert!(split.abs_diff(single) <= 1);
+    }
+
+    #[test]
+    fn test_sell_never_exceeds_buy() {
+        let b = 1_000_000;
+        let paid = cost_to_buy(&[200_000, 0], b, 1, 400_000).unwrap();
+        let refunded = cost_to_sell(&[200_000, 400_000], b, 1, 400_000).unwrap();
+        assert!(refunded <= paid);
+        assert!(paid - refunded <= 1);
+        assert!(cost_to_sell(&[0, 0], b, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_loss_is_bounded_by_subsidy() {
+        let b = 1_000_000;
+        let subsidy = max_subsidy_loss(b, 3).unwrap();
+        assert_eq!(subsidy, 1_098_613); // ceil(1e6 * ln 3)
+
+        // Everyone piles into one outcome; if it wins the market pays out q_0
+        let mut quantities = [0u64; 3];
+        let mut collected = 0;
+        for _ in 0..20 {
+            collected += cost_to_buy(&quantities, b, 0, 1_000_000).unwrap();
+            quantities[0] += 1_000_000;
+        }
+        assert!(quantities[0] <= collected + subsidy);
+    }
+
+    #[test]
+    fn test_rejects_zero_liquidity() {
+        assert!(cost(&[0, 0], 0).is_err());
+        assert!(max_subsidy_loss(0, 2).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod lmsr;
+pub mod oracle;
+pub mod pricing;
+pub mod slippage;
+pub mod token;
@@ -1 +1,142 @@
-I'm sorry. I'm not able to assist with that.
\ No newline at end of file
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+
+// Reader for Pyth-style (v2) price accounts. Only the header and the
+// aggregate price are decoded, straight from the account bytes, so any
+// account with the same layout works as a feed; a local fixture account is
+// enough in tests. Which account a market trusts is fixed at creation, and
+// which program must own it is `Config::oracle_program`, so tests point that
+// at their fixture's owner.
+
+/// Magic number at the start of every price account.
+pub const PRICE_ACCOUNT_MAGIC: u32 = 0xa1b2c3d4;
+
+/// Account type tag of a price account.
+pub const PRICE_ACCOUNT_TYPE: u32 = 3;
+
+/// Aggregate status meaning the price is live.
+pub const PRICE_STATUS_TRADING: u32 = 1;
+
+const MAGIC_OFFSET: usize = 0;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+
+/// Bytes needed to read everything above.
+pub const PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Aggregate price of a feed. `price` and `conf` are fixed point with
+/// exponent `expo`, i.e. the real price is `price * 10^expo`. Markets compare
+/// and store the raw `price`; the exponent is never applied on-chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceFeed {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub status: u32,
+    pub publish_time: i64,
+}
+
+impl PriceFeed {
+    /// Accepts the price only if it is live, was published within
+    /// `max_staleness` seconds after `end_time`, and its confidence interval
+    /// is at most `max_confidence_bps` of the price.
+    pub fn validate(&self, end_time: i64, max_staleness: u32, max_confidence_bps: u16) -> Result<()> {
+        require!(self.status == PRICE_STATUS_TRADING, ZentroError::OraclePriceUnavailable);
+        require!(self.publish_time >= end_time, ZentroError::OracleResolutionPending);
+        require!(
+            self.publish_time - end_time <= max_staleness as i64,
+            ZentroError::OraclePriceStale
+        );
+
+        let max_conf = (self.price.unsigned_abs() as u128) * (max_confidence_bps as u128) / 10000;
+        require!(
+            (self.conf as u128) <= max_conf,
+            ZentroError::OracleConfidenceTooWide
+        );
+
+        Ok(())
+    }
+}
+
+/// Decodes the aggregate price from raw price-account data.
+pub fn load_price_feed(data: &[u8]) -> Result<PriceFeed> {
+    require!(data.len() >= PRICE_ACCOUNT_MIN_LEN, ZentroError::InvalidOracleAccount);
+    require!(
+        read_u32(data, MAGIC_OFFSET) == PRICE_ACCOUNT_MAGIC
+            && read_u32(data, ACCOUNT_TYPE_OFFSET) == PRICE_ACCOUNT_TYPE,
+        ZentroError::InvalidOracleAccount
+    );
+
+    Ok(PriceFeed {
+        price: read_i64(data, AGG_PRICE_OFFSET),
+        conf: read_u64(data, AGG_CONF_OFFSET),
+        expo: read_u32(data, EXPO_OFFSET) as i32,
+        status: read_u32(data, AGG_STATUS_OFFSET),
+        publish_time: read_i64(data, TIMESTAMP_OFFSET),
+    })
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_i64(data: &[u8], offset: usize) -> i64 {
+    i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::price_feed;
+
+    /// Serializes `feed` into a price account, for local fixture accounts.
+    fn write_price_feed(feed: &PriceFeed) -> Vec<u8> {
+        let mut data = vec![0u8; PRICE_ACCOUNT_MIN_LEN];
+        data[MAGIC_OFFSET..MAGIC_OFFSET + 4].copy_from_slice(&PRICE_ACCOUNT_MAGIC.to_le_bytes());
+        data[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4].copy_from_slice(&PRICE_ACCOUNT_TYPE.to_le_bytes());
+        data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&feed.expo.to_le_bytes());
+        data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&feed.publish_time.to_le_bytes());
+        data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&feed.price.to_le_bytes());
+        data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&feed.conf.to_le_bytes());
+        data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&feed.status.to_le_bytes());
+        data
+    }
+
+
+    #[test]
+    fn test_load_price_feed_round_trip() {
+        let feed = price_feed(6_512_345_000_000, 1_200_000_000, 1_700_000_000);
+        assert_eq!(load_price_feed(&write_price_feed(&feed)).unwrap(), feed);
+    }
+
+    #[test]
+    fn test_load_rejects_other_accounts() {
+        let mut data = write_price_feed(&price_feed(100, 1, 0));
+        data[0] = 0;
+        assert!(load_price_feed(&data).is_err());
+        assert!(load_price_feed(&[0u8; 16]).is_err());
+    }
+
+    #[test]
+    fn test_validate_window_and_confidence() {
+        let end_time = 1_000;
+        // 1% confidence allowed, 10s window after end_time
+        assert!(price_feed(10_000, 100, 1_005).validate(end_time, 10, 100).is_ok());
+        assert!(price_feed(10_000, 100, 999).validate(end_time, 10, 100).is_err());
+        assert!(price_feed(10_000, 100, 1_011).validate(end_time, 10, 100).is_err());
+        assert!(price_feed(10_000, 101, 1_005).validate(end_time, 10, 100).is_err());
+        assert!(price_feed(-10_000, 100, 1_005).validate(end_time, 10, 100).is_ok());
+
+        let mut halted = price_feed(10_000, 0, 1_005);
+        halted.status = 2;
+        assert!(halted.validate(end_time, 10, 100).is_err());
+    }
+}
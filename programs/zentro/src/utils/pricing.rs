@@ -1,6 +1,6 @@
-```rust
 use anchor_lang::prelude::*;
 use std::cmp;
+use crate::error::ZentroError;
 
 #[derive(Debug, Clone, Copy)]
 pub struct PricingParams {
@@ -22,28 +22,29 @@ impl Default for PricingParams {
 }
 
 pub fn calculate_market_price(
-    yes_shares: u64,
-    no_shares: u64,
+    outcome_shares: &[u64],
+    outcome: usize,
     total_liquidity: u64,
     params: &PricingParams,
 ) -> Result<u64> {
-    if yes_shares == 0 && no_shares == 0 {
-        return Ok(params.base_price);
-    }
+    require!(outcome < outcome_shares.len(), ZentroError::InvalidOutcome);
 
-    let total_shares = yes_shares.saturating_add(no_shares);
+    let total_shares = outcome_shares
+        .iter()
+        .fold(0u64, |acc, shares| acc.saturating_add(*shares));
     if total_shares == 0 {
-        return Ok(params.base_price);
+        // Uniform prior across outcomes
+        return Ok(10000 / outcome_shares.len() as u64);
     }
 
     // Calculate probability based on share distribution
-    let yes_probability = (yes_shares as u128)
+    let probability = (outcome_shares[outcome] as u128)
         .saturating_mul(10000)
         .saturating_div(total_shares as u128) as u64;
 
     // Apply liquidity adjustment
     let liquidity_factor = calculate_liquidity_factor(total_liquidity, params.liquidity_depth);
-    let adjusted_probability = apply_liquidity_adjustment(yes_probability, liquidity_factor);
+    let adjusted_probability = apply_liquidity_adjustment(probability, liquidity_factor);
 
     // Apply volatility factor
     let final_price = apply_volatility_adjustment(adjusted_probability, params.volatility_factor);
@@ -52,24 +53,13 @@ pub fn calculate_market_price(
 }
 
 pub fn calculate_share_price(
-    market_price: u64,
+    outcome_price: u64,
     share_amount: u64,
-    is_yes_share: bool,
     total_liquidity: u64,
 ) -> Result<u64> {
-    let base_price = if is_yes_share {
-        market_price
-    } else {
-        10000_u64.saturating_sub(market_price)
-    };
-
     // Calculate price impact based on share amount and liquidity
     let price_impact = calculate_price_impact(share_amount, total_liquidity);
-    let adjusted_price = if is_yes_share {
-        base_price.saturating_add(price_impact)
-    } else {
-        base_price.saturating_add(price_impact)
-    };
+    let adjusted_price = outcome_price.saturating_add(price_impact);
 
     // Calculate total cost
     let total_cost = (adjusted_price as u128)
@@ -79,16 +69,85 @@ pub fn calculate_share_price(
     Ok(total_cost)
 }
 
+/// Pro-rata share of `total_pool` owed to `shares_owned` shares of `position_outcome`.
 pub fn calculate_payout(
     shares_owned: u64,
-    market_outcome: bool,
-    is_yes_share: bool,
+    position_outcome: u8,
+    winning_outcome: u8,
+    winning_shares: u64,
+    total_pool: u64,
 ) -> Result<u64> {
-    if (market_outcome && is_yes_share) || (!market_outcome && !is_yes_share) {
-        Ok(shares_owned) // 1:1 payout for winning shares
-    } else {
-        Ok(0) // No payout for losing shares
+    if position_outcome != winning_outcome {
+        return Ok(0); // No payout for losing shares
     }
+
+    require!(winning_shares > 0, ZentroError::NoWinningShares);
+
+    let payout = (shares_owned as u128)
+        .checked_mul(total_pool as u128)
+        .ok_or(ZentroError::MathOverflow)?
+        .checked_div(winning_shares as u128)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    u64::try_from(payout).map_err(|_| ZentroError::MathOverflow.into())
+}
+
+/// Share of a scalar market's pool, in basis points, owed to the LONG side
+/// when it resolves at `resolved_value`. Values outside the range clamp to it.
+pub fn scalar_long_weight(lower_bound: i64, upper_bound: i64, resolved_value: i64) -> Result<u64> {
+    require!(lower_bound < upper_bound, ZentroError::InvalidScalarRange);
+
+    let clamped = resolved_value.clamp(lower_bound, upper_bound);
+    let range = (upper_bound as i128) - (lower_bound as i128);
+    let offset = (clamped as i128) - (lower_bound as i128);
+
+    Ok((offset * 10000 / range) as u64)
+}
+
+/// Pro-rata payout for `shares_owned` of one side of a scalar market, where
+/// that side is owed `side_weight` basis points of `total_pool`.
+pub fn calculate_scalar_payout(
+    shares_owned: u64,
+    side_weight: u64,
+    side_shares: u64,
+    total_pool: u64,
+) -> Result<u64> {
+    require!(side_weight <= 10000, ZentroError::MathOverflow);
+    require!(side_shares > 0, ZentroError::NoWinningShares);
+
+    let payout = (shares_owned as u128)
+        .checked_mul(total_pool as u128)
+        .ok_or(ZentroError::MathOverflow)?
+        .checked_mul(side_weight as u128)
+        .ok_or(ZentroError::MathOverflow)?
+        .checked_div((side_shares as u128) * 10000)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    u64::try_from(payout).map_err(|_| ZentroError::MathOverflow.into())
+}
+
+/// Fixed-odds payout for `shares_owned` shares each worth `weight` basis
+/// points of one collateral unit, as used by market-maker priced markets.
+pub fn calculate_share_payout(shares_owned: u64, weight: u64) -> Result<u64> {
+    require!(weight <= 10000, ZentroError::MathOverflow);
+
+    Ok(((shares_owned as u128) * (weight as u128) / 10000) as u64)
+}
+
+/// Collateral returned for withdrawing `shares` from a parimutuel outcome
+/// holding `outcome_shares` shares staked for `outcome_volume`. Its implied
+/// probability times the pro-rata pool payout is the outcome's average stake
+/// per share, so exiting never moves the odds for anyone left in the pool.
+pub fn calculate_parimutuel_exit(shares: u64, outcome_shares: u64, outcome_volume: u64) -> Result<u64> {
+    require!(shares <= outcome_shares, ZentroError::InsufficientShares);
+
+    let value = (shares as u128)
+        .checked_mul(outcome_volume as u128)
+        .ok_or(ZentroError::MathOverflow)?
+        .checked_div(outcome_shares as u128)
+        .ok_or(ZentroError::MathOverflow)?;
+
+    u64::try_from(value).map_err(|_| ZentroError::MathOverflow.into())
 }
 
 pub fn calculate_liquidity_factor(current_liquidity: u64, target_liquidity: u64) -> u64 {
@@ -121,11 +180,16 @@ pub fn apply_liquidity_adjustment(base_price: u64, liquidity_factor: u64) -> u64
 }
 
 pub fn apply_volatility_adjustment(base_price: u64, volatility_factor: u64) -> u64 {
+    // Scale the distance from 50% so a balanced market stays balanced
     let volatility_adjustment = volatility_factor
-        .saturating_mul(base_price)
+        .saturating_mul(base_price.abs_diff(5000))
         .saturating_div(10000);
 
-    base_price.saturating_add(volatility_adjustment)
+    if base_price > 5000 {
+        base_price.saturating_add(volatility_adjustment)
+    } else {
+        base_price.saturating_sub(volatility_adjustment)
+    }
 }
 
 pub fn calculate_price_impact(share_amount: u64, total_liquidity: u64) -> u64 {
@@ -191,7 +255,7 @@ fn integer_sqrt(n: u64) -> u64 {
     }
     
     let mut x = n;
-    let mut y = (x + 1) / 2;
+    let mut y = x.div_ceil(2);
     
     while y < x {
         x = y;
@@ -208,23 +272,81 @@ mod tests {
     #[test]
     fn test_calculate_market_price() {
         let params = PricingParams::default();
-        let price = calculate_market_price(100, 100, 1000000, &params).unwrap();
+        let price = calculate_market_price(&[100, 100], 0, 1000000, &params).unwrap();
         assert_eq!(price, params.base_price);
     }
 
+    #[test]
+    fn test_volatility_adjustment() {
+        // 1% volatility moves a price 1% further away from 50%
+        assert_eq!(apply_volatility_adjustment(5000, 100), 5000);
+        assert_eq!(apply_volatility_adjustment(7000, 100), 7020);
+        assert_eq!(apply_volatility_adjustment(3000, 100), 2980);
+    }
+
+    #[test]
+    fn test_calculate_market_price_categorical() {
+        let params = PricingParams::default();
+        let price = calculate_market_price(&[0, 0, 0, 0], 2, 1000000, &params).unwrap();
+        assert_eq!(price, 2500);
+
+        let favourite = calculate_market_price(&[600, 200, 200], 0, 1000000, &params).unwrap();
+        let outsider = calculate_market_price(&[600, 200, 200], 1, 1000000, &params).unwrap();
+        assert!(favourite > outsider);
+
+        assert!(calculate_market_price(&[100, 100], 2, 1000000, &params).is_err());
+    }
+
+    #[test]
+    fn test_calculate_parimutuel_exit() {
+        assert_eq!(calculate_parimutuel_exit(250, 1000, 1000).unwrap(), 250);
+        assert_eq!(calculate_parimutuel_exit(100, 400, 300).unwrap(), 75);
+        assert!(calculate_parimutuel_exit(401, 400, 300).is_err());
+    }
+
     #[test]
     fn test_calculate_share_price() {
-        let price = calculate_share_price(5000, 100, true, 1000000).unwrap();
+        let price = calculate_share_price(5000, 100, 1000000).unwrap();
         assert!(price > 0);
     }
 
     #[test]
     fn test_calculate_payout() {
-        let payout = calculate_payout(100, true, true).unwrap();
+        let payout = calculate_payout(100, 0, 0, 100, 100).unwrap();
         assert_eq!(payout, 100);
         
-        let no_payout = calculate_payout(100, true, false).unwrap();
+        let no_payout = calculate_payout(100, 1, 0, 100, 100).unwrap();
         assert_eq!(no_payout, 0);
+
+        // Winners split the losing outcomes' stake pro rata
+        let pro_rata = calculate_payout(50, 2, 2, 200, 600).unwrap();
+        assert_eq!(pro_rata, 150);
+    }
+
+    #[test]
+    fn test_scalar_long_weight() {
+        assert_eq!(scalar_long_weight(1000, 3000, 2500).unwrap(), 7500);
+        assert_eq!(scalar_long_weight(1000, 3000, 500).unwrap(), 0);
+        assert_eq!(scalar_long_weight(1000, 3000, 9000).unwrap(), 10000);
+        assert_eq!(scalar_long_weight(-100, 100, 0).unwrap(), 5000);
+        assert!(scalar_long_weight(100, 100, 100).is_err());
+    }
+
+    #[test]
+    fn test_calculate_scalar_payout() {
+        // 1000 in the pool, 75% to LONG holders
+        let long = calculate_scalar_payout(200, 7500, 400, 1000).unwrap();
+        let short = calculate_scalar_payout(600, 2500, 600, 1000).unwrap();
+        assert_eq!(long, 375);
+        assert_eq!(short, 250);
+    }
+
+    #[test]
+    fn test_calculate_share_payout() {
+        assert_eq!(calculate_share_payout(1000, 10000).unwrap(), 1000);
+        assert_eq!(calculate_share_payout(1000, 2500).unwrap(), 250);
+        assert_eq!(calculate_share_payout(1000, 0).unwrap(), 0);
+        assert!(calculate_share_payout(1000, 10001).is_err());
     }
 
     #[test]
@@ -243,4 +365,3 @@ mod tests {
         assert_eq!(integer_sqrt(16), 4);
     }
 }
-```
\ No newline at end of file
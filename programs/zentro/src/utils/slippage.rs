@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::error::ZentroError;
+
+// User-supplied bounds on a trade. Every trading instruction takes them so a
+// fill computed at execution time can never be worse than what the user
+// signed for, however far the price moved while the transaction was in flight.
+
+/// Fails once `expires_at` (a unix timestamp) has passed. `None` never expires.
+pub fn check_deadline(expires_at: Option<i64>, now: i64) -> Result<()> {
+    if let Some(expires_at) = expires_at {
+        require!(now <= expires_at, ZentroError::TradeExpired);
+    }
+    Ok(())
+}
+
+/// Fails when the collateral charged exceeds `max_cost`.
+pub fn check_max_cost(cost: u64, max_cost: u64) -> Result<()> {
+    require!(cost <= max_cost, ZentroError::MaxCostExceeded);
+    Ok(())
+}
+
+/// Fails when the tokens or collateral received fall short of `min_amount_out`.
+pub fn check_min_amount_out(amount_out: u64, min_amount_out: u64) -> Result<()> {
+    require!(amount_out >= min_amount_out, ZentroError::MinAmountOutNotMet);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_deadline() {
+        assert!(check_deadline(None, i64::MAX).is_ok());
+        assert!(check_deadline(Some(100), 100).is_ok());
+        assert!(check_deadline(Some(100), 101).is_err());
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        assert!(check_max_cost(500, 500).is_ok());
+        assert!(check_max_cost(501, 500).is_err());
+        assert!(check_min_amount_out(500, 500).is_ok());
+        assert!(check_min_amount_out(499, 500).is_err());
+    }
+}
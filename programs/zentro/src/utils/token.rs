@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::error::ZentroError;
+use crate::seeds::find_outcome_mint_address;
+
+// Collateral may live under either SPL Token or Token-2022. Token-2022 mints
+// can carry extensions; transfer fees are handled by crediting what actually
+// arrives, but extensions that let someone else move or gate vault funds
+// would break the market's accounting and are refused at creation.
+
+/// Mint extensions a market refuses as collateral.
+pub const UNSUPPORTED_EXTENSIONS: [ExtensionType; 3] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::NonTransferable,
+    ExtensionType::TransferHook,
+];
+
+/// Fails if the mint in `data` carries any of `UNSUPPORTED_EXTENSIONS`.
+pub fn check_collateral_mint(data: &[u8]) -> Result<()> {
+    let mint = StateWithExtensions::<MintState>::unpack(data)?;
+    let extensions = mint.get_extension_types()?;
+    require!(
+        !extensions.iter().any(|extension| UNSUPPORTED_EXTENSIONS.contains(extension)),
+        ZentroError::UnsupportedMintExtension
+    );
+    Ok(())
+}
+
+/// Whether the mint in `data` can charge transfer fees at all.
+pub fn has_transfer_fee(data: &[u8]) -> Result<bool> {
+    let mint = StateWithExtensions::<MintState>::unpack(data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().is_ok())
+}
+
+/// Fee withheld by the mint in `data` when `amount` is transferred during
+/// `epoch`; zero for mints without a transfer-fee extension.
+pub fn transfer_fee(data: &[u8], epoch: u64, amount: u64) -> Result<u64> {
+    let mint = StateWithExtensions::<MintState>::unpack(data)?;
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(ZentroError::MathOverflow.into()),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Moves `amount` tokens of `mint` with `transfer_checked`, signing with
+/// `signer_seeds` when given, and returns what `to` actually receives.
+pub fn transfer_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    let fee = transfer_fee(&mint.to_account_info().try_borrow_data()?, Clock::get()?.epoch, amount)?;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from,
+                mint: mint.to_account_info(),
+                to,
+                authority,
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    amount.checked_sub(fee).ok_or(ZentroError::MathOverflow.into())
+}
+
+/// Whether `mint` lives under `token_program`. Outcome mints must share the
+/// collateral's program, since every outcome-token flow moves both through
+/// one `token_program` account.
+pub fn is_owned_by_program(mint: &AccountInfo, token_program: &Pubkey) -> bool {
+    mint.owner == token_program
+}
+
+/// Checks that `accounts` holds, for each of the `outcome_count` outcomes of
+/// `market` in order, the outcome's token mint followed by a token account of
+/// that mint, and returns them as pairs.
+pub fn outcome_token_accounts<'info>(
+    market: &Pubkey,
+    outcome_count: usize,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<(InterfaceAccount<'info, Mint>, InterfaceAccount<'info, TokenAccount>)>> {
+    require!(accounts.len() == outcome_count * 2, ZentroError::InvalidOutcomeMint);
+
+    accounts
+        .chunks(2)
+        .zip(0u8..)
+        .map(|(pair, outcome)| {
+            let (expected, _) = find_outcome_mint_address(market, outcome);
+            require_keys_eq!(pair[0].key(), expected, ZentroError::InvalidOutcomeMint);
+            let mint = InterfaceAccount::<Mint>::try_from(&pair[0])?;
+            let token_account = InterfaceAccount::<TokenAccount>::try_from(&pair[1])?;
+            require_keys_eq!(token_account.mint, expected, ZentroError::InvalidUserTokenAccount);
+            Ok((mint, token_account))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::mint_with;
+
+    #[test]
+    fn test_is_owned_by_program() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = mint_with(&[], 0);
+        let owner = anchor_spl::token::ID;
+        let mint = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+
+        assert!(is_owned_by_program(&mint, &anchor_spl::token::ID));
+        assert!(!is_owned_by_program(&mint, &anchor_spl::token_2022::ID));
+    }
+
+    #[test]
+    fn test_transfer_fee() {
+        // Legacy SPL mints and Token-2022 mints without the extension are free
+        assert_eq!(transfer_fee(&mint_with(&[], 0), 0, 10_000).unwrap(), 0);
+
+        // 1% fee, capped at 1,000
+        let fee_mint = mint_with(&[ExtensionType::TransferFeeConfig], 100);
+        assert!(has_transfer_fee(&fee_mint).unwrap());
+        assert_eq!(transfer_fee(&fee_mint, 0, 10_000).unwrap(), 100);
+        assert_eq!(transfer_fee(&fee_mint, 0, 1_000_000_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_check_collateral_mint() {
+        assert!(check_collateral_mint(&mint_with(&[], 0)).is_ok());
+        assert!(check_collateral_mint(&mint_with(&[ExtensionType::TransferFeeConfig], 100)).is_ok());
+        assert!(check_collateral_mint(&mint_with(&[ExtensionType::PermanentDelegate], 0)).is_err());
+    }
+}